@@ -3,65 +3,251 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use crate::type_length;
+use crate::{type_from_usize, type_into_usize, type_length};
 use proc_macro2::TokenStream;
-use quote::{format_ident, quote};
-use syn::{DataEnum, Fields, FieldsNamed, FieldsUnnamed, Ident, Variant};
+use quote::{format_ident, quote, quote_spanned};
+use syn::spanned::Spanned;
+use syn::{
+    parse_quote, DataEnum, Fields, FieldsNamed, FieldsUnnamed, Generics, Ident, Type, Variant,
+    WherePredicate,
+};
 
-pub fn generate(name: Ident, data_enum: DataEnum) -> TokenStream {
-    let mut generator = EnumGenerator::empty();
+pub fn generate(
+    name: Ident,
+    generics: &Generics,
+    data_enum: DataEnum,
+    krate: &TokenStream,
+    default: Option<&Ident>,
+) -> TokenStream {
+    let default_impl = match default {
+        Some(default) => default_impl(&name, generics, &data_enum, default)
+            .unwrap_or_else(syn::Error::into_compile_error),
+        None => quote! {},
+    };
+    let mut generator = EnumGenerator::empty(generics, krate);
     for variant in &data_enum.variants {
         generator.handle_variant(variant);
     }
-    generator.finish(&name)
+    let enum_impl = generator.finish(&name, generics);
+    quote! {
+        #enum_impl
+        #default_impl
+    }
+}
+
+/// Implements `Default` returning the unit variant given with `#[enum_map(default = Variant)]`.
+fn default_impl(
+    name: &Ident,
+    generics: &Generics,
+    data_enum: &DataEnum,
+    default: &Ident,
+) -> syn::Result<TokenStream> {
+    let variant = data_enum
+        .variants
+        .iter()
+        .find(|variant| variant.ident == *default)
+        .ok_or_else(|| syn::Error::new_spanned(default, "no variant with this name"))?;
+    if !matches!(variant.fields, Fields::Unit) {
+        return Err(syn::Error::new_spanned(
+            default,
+            "default variant needs to be a unit variant",
+        ));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics ::core::default::Default for #name #ty_generics #where_clause {
+            #[inline]
+            fn default() -> Self {
+                Self::#default
+            }
+        }
+    })
 }
 
 /// Total length is the sum of each variant's length. To represent a variant, its number is added to
 /// the sum of previous variant lengths.
+///
+/// Sums of previous variant lengths are stored in constants, as otherwise each variant would
+/// repeat an ever-growing length expression. Constants outside of an impl cannot refer to
+/// generic parameters, so for generic enums the lengths are written out instead.
+///
+/// Array lengths cannot depend on generic parameters either, so generic enums use an array type
+/// built out of array types of field types, and require field types to implement `Enum` and
+/// `EnumArray`.
 #[derive(Debug)]
 struct EnumGenerator {
     length: TokenStream,
+    length_consts: TokenStream,
+    variant_count: usize,
     from_usize_arms: TokenStream,
     into_usize_arms: TokenStream,
+    unit_values: TokenStream,
+    has_fields: bool,
+    is_generic: bool,
+    array: TokenStream,
+    enum_bounds: Vec<TokenStream>,
+    array_bounds: Vec<TokenStream>,
+    krate: TokenStream,
 }
 
 impl EnumGenerator {
-    fn empty() -> Self {
+    fn empty(generics: &Generics, krate: &TokenStream) -> Self {
+        let value = value_ident();
         Self {
             length: quote! { 0usize },
+            length_consts: quote! {},
+            variant_count: 0,
             from_usize_arms: quote! {},
             into_usize_arms: quote! {},
+            unit_values: quote! {},
+            has_fields: false,
+            is_generic: !generics.params.is_empty(),
+            array: quote! { [#value; 0] },
+            enum_bounds: Vec::new(),
+            array_bounds: Vec::new(),
+            krate: krate.clone(),
         }
     }
 
-    fn finish(&self, name: &Ident) -> TokenStream {
+    fn finish(&self, name: &Ident, generics: &Generics) -> TokenStream {
         let length = &self.length;
+        let length_consts = &self.length_consts;
         let from_usize_arms = &self.from_usize_arms;
         let into_usize_arms = &self.into_usize_arms;
+        let const_impl = self.const_impl(name, generics);
+        let enum_array_impl = self.enum_array_impl(name, generics);
+        let mut enum_generics = generics.clone();
+        enum_generics
+            .make_where_clause()
+            .predicates
+            .extend(self.enum_bounds.iter().map(|bound| -> WherePredicate {
+                parse_quote! { #bound }
+            }));
+        let (impl_generics, ty_generics, where_clause) = enum_generics.split_for_impl();
+        let krate = &self.krate;
 
         quote! {
-            #[automatically_derived]
-            impl ::enum_map::Enum for #name {
-                const LENGTH: ::enum_map::usize = #length;
+            const _: () = {
+                #length_consts
+
+                #[automatically_derived]
+                impl #impl_generics #krate::Enum for #name #ty_generics #where_clause {
+                    const LENGTH: #krate::usize = #length;
 
-                #[inline]
-                fn from_usize(value: ::enum_map::usize) -> Self {
-                    #from_usize_arms {
-                        ::enum_map::out_of_bounds()
+                    #[inline]
+                    fn from_usize(value: #krate::usize) -> Self {
+                        #from_usize_arms {
+                            #krate::out_of_bounds()
+                        }
                     }
-                }
 
-                #[inline]
-                fn into_usize(self) -> ::enum_map::usize {
-                    match self {
-                        #into_usize_arms
+                    #[inline]
+                    fn into_usize(self) -> #krate::usize {
+                        match self {
+                            #into_usize_arms
+                        }
                     }
                 }
+
+                #enum_array_impl
+
+                #const_impl
+            };
+        }
+    }
+
+    fn enum_array_impl(&self, name: &Ident, generics: &Generics) -> TokenStream {
+        let krate = &self.krate;
+        if !self.is_generic {
+            let length = &self.length;
+            return quote! {
+                #[automatically_derived]
+                impl<V> #krate::EnumArray<V> for #name {
+                    type Array = [V; #length];
+                }
+
+                #[automatically_derived]
+                impl #krate::EnumSetArray for #name {
+                    type Words = [#krate::usize; #krate::word_count(#length)];
+                }
+            };
+        }
+        let value = value_ident();
+        let array = &self.array;
+        let (_, ty_generics, _) = generics.split_for_impl();
+        let mut generics = generics.clone();
+        generics.params.push(parse_quote! { #value });
+        generics.make_where_clause().predicates.extend(
+            self.enum_bounds
+                .iter()
+                .chain(&self.array_bounds)
+                .map(|bound| -> WherePredicate {
+                    parse_quote! { #bound }
+                }),
+        );
+        let (impl_generics, _, where_clause) = generics.split_for_impl();
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics #krate::EnumArray<#value> for #name #ty_generics #where_clause {
+                type Array = #array;
             }
+        }
+    }
+
+    /// Adds an array type of a variant, placing its values after values of previous variants.
+    fn push_array(&mut self, variant_array: TokenStream) {
+        let previous = &self.array;
+        let krate = &self.krate;
+        self.array = quote! { #krate::SumArray<#previous, #variant_array> };
+    }
 
+    /// Returns an array type storing an array of `inner` for each value of a field type.
+    fn field_array(&mut self, ty: &Type, inner: &TokenStream) -> TokenStream {
+        let krate = &self.krate;
+        if self.is_generic {
+            let span = ty.span();
+            self.enum_bounds
+                .push(quote_spanned! { span=> #ty: #krate::Enum });
+            self.array_bounds
+                .push(quote_spanned! { span=> #ty: #krate::EnumArray<#inner> });
+        }
+        quote! {
+            #krate::ProductArray<<#ty as #krate::EnumArray<#inner>>::Array, #inner>
+        }
+    }
+
+    /// Adds a variant with a given length, storing the new total length in a constant.
+    fn push_length(&mut self, variant_length: TokenStream) {
+        let previous = &self.length;
+        if self.is_generic {
+            self.length = quote! { (#previous + #variant_length) };
+            return;
+        }
+        let end = format_ident!("VARIANT_{}_END", self.variant_count);
+        let length_consts = &self.length_consts;
+        let krate = &self.krate;
+        self.length_consts = quote! {
+            #length_consts
+            const #end: #krate::usize = #previous + #variant_length;
+        };
+        self.length = quote! { #end };
+        self.variant_count += 1;
+    }
+
+    /// Unit variants can be listed in a constant, so enums without fields implement `ConstEnum`
+    /// used by `const_enum_map!`.
+    fn const_impl(&self, name: &Ident, generics: &Generics) -> TokenStream {
+        if self.has_fields {
+            return quote! {};
+        }
+        let unit_values = &self.unit_values;
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        let krate = &self.krate;
+        quote! {
             #[automatically_derived]
-            impl<V> ::enum_map::EnumArray<V> for #name {
-                type Array = [V; #length];
+            impl #impl_generics #krate::ConstEnum for #name #ty_generics #where_clause {
+                const VALUES: &'static [Self] = &[#unit_values];
             }
         }
     }
@@ -79,42 +265,53 @@ impl EnumGenerator {
         let into_arms = &self.into_usize_arms;
         let length = &self.length;
         self.into_usize_arms = quote! { #into_arms Self::#variant => #length, };
+        let unit_values = &self.unit_values;
+        self.unit_values = quote! { #unit_values Self::#variant, };
         let from_arms = &self.from_usize_arms;
         self.from_usize_arms = quote! {
             #from_arms if value == #length {
                 Self::#variant
             } else
         };
-        self.length = quote! { (#length + 1) };
+        self.push_length(quote! { 1usize });
+        let value = value_ident();
+        self.push_array(quote! { [#value; 1] });
     }
 
     /// Its size is the product of the sizes of its members. To represent this variant, one can
     /// think of this as representing a little-endian number. First member is simply added, but
     /// next members are multiplied before being added.
     fn handle_unnamed_variant(&mut self, variant: &Ident, fields: &FieldsUnnamed) {
-        let length = &self.length;
+        self.has_fields = true;
+        let length = self.length.clone();
         let mut expr_into = quote! { #length };
         let mut fields_length = quote! { 1usize };
         let mut params_from = quote! {};
+        let value = value_ident();
+        let mut array = quote! { [#value; 1] };
         for (i, field) in fields.unnamed.iter().enumerate() {
             let ident = format_ident!("p{}", i);
             let ty = &field.ty;
-            let field_length = type_length(ty);
+            let field_length = type_length(&self.krate, ty);
+            array = self.field_array(ty, &array);
 
+            let field_into = type_into_usize(&self.krate, ty, &quote! { #ident });
             expr_into = quote! {
-                (#expr_into + #fields_length * ::enum_map::Enum::into_usize(#ident))
+                (#expr_into + #fields_length * #field_into)
             };
 
-            params_from = quote! {
-                #params_from <#ty as ::enum_map::Enum>::from_usize(
-                    (value - #length) / #fields_length % #field_length
-                ),
-            };
+            let field_from = type_from_usize(
+                &self.krate,
+                ty,
+                &quote! { (value - #length) / #fields_length % #field_length },
+            );
+            params_from = quote! { #params_from #field_from, };
 
             fields_length = quote! { (#fields_length * #field_length) };
         }
 
-        self.length = quote! { (#length + #fields_length) };
+        self.push_length(fields_length);
+        self.push_array(array);
 
         let length = &self.length;
         let from_arms = &self.from_usize_arms;
@@ -140,30 +337,37 @@ impl EnumGenerator {
     /// think of this as representing a little-endian number. First member is simply added, but
     /// next members are multiplied before being added.
     fn handle_named_variant(&mut self, variant: &Ident, fields: &FieldsNamed) {
-        let length = &self.length;
+        self.has_fields = true;
+        let length = self.length.clone();
         let mut expr_into = quote! { #length };
         let mut fields_length = quote! { 1usize };
         let mut params_from = quote! {};
+        let value = value_ident();
+        let mut array = quote! { [#value; 1] };
 
         for field in fields.named.iter() {
             let ident = field.ident.as_ref().unwrap();
             let ty = &field.ty;
-            let field_length = type_length(ty);
+            let field_length = type_length(&self.krate, ty);
+            array = self.field_array(ty, &array);
 
+            let field_into = type_into_usize(&self.krate, ty, &quote! { #ident });
             expr_into = quote! {
-                (#expr_into + #fields_length * ::enum_map::Enum::into_usize(#ident))
+                (#expr_into + #fields_length * #field_into)
             };
 
-            params_from = quote! {
-                #params_from #ident: <#ty as ::enum_map::Enum>::from_usize(
-                    (value - #length) / #fields_length % #field_length
-                ),
-            };
+            let field_from = type_from_usize(
+                &self.krate,
+                ty,
+                &quote! { (value - #length) / #fields_length % #field_length },
+            );
+            params_from = quote! { #params_from #ident: #field_from, };
 
             fields_length = quote! { (#fields_length * #field_length) };
         }
 
-        self.length = quote! { (#length + #fields_length) };
+        self.push_length(fields_length);
+        self.push_array(array);
 
         let length = &self.length;
         let from_arms = &self.from_usize_arms;
@@ -185,3 +389,8 @@ impl EnumGenerator {
         };
     }
 }
+
+/// Type parameter for values of an enum map in `EnumArray` implementation of a generic enum.
+fn value_ident() -> Ident {
+    format_ident!("EnumMapValue")
+}
@@ -3,127 +3,154 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use crate::type_length;
+use crate::{type_from_usize, type_into_usize, type_length};
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{format_ident, quote};
 use syn::{DataStruct, Fields, FieldsNamed, FieldsUnnamed, Ident, Index};
 
-pub fn generate(name: Ident, data_struct: DataStruct) -> TokenStream {
-    StructGenerator::from_fields(&data_struct.fields).finish(&name)
+pub fn generate(name: Ident, data_struct: DataStruct, krate: &TokenStream) -> TokenStream {
+    StructGenerator::from_fields(&data_struct.fields, krate).finish(&name, krate)
 }
 
 /// Total length is the product of each member's length. To represent a struct, one can
 /// think of this as representing a little-endian number. First member is simply added, but
 /// next members are multiplied before being added.
+///
+/// Products of previous member lengths are stored in constants, as otherwise each member would
+/// repeat an ever-growing length expression.
 #[derive(Debug)]
 struct StructGenerator {
     length: TokenStream,
+    length_consts: TokenStream,
     from_usize: TokenStream,
     into_usize: TokenStream,
 }
 
 impl StructGenerator {
-    fn from_fields(fields: &Fields) -> Self {
+    fn from_fields(fields: &Fields, krate: &TokenStream) -> Self {
         match fields {
             Fields::Unit => Self::from_unit_fields(),
-            Fields::Unnamed(fields_data) => Self::from_unnamed_fields(fields_data),
-            Fields::Named(fields_data) => Self::from_named_fields(fields_data),
+            Fields::Unnamed(fields_data) => Self::from_unnamed_fields(fields_data, krate),
+            Fields::Named(fields_data) => Self::from_named_fields(fields_data, krate),
         }
     }
 
     fn from_unit_fields() -> Self {
         Self {
             length: quote! { 1usize },
+            length_consts: quote! {},
             from_usize: quote! { Self },
             into_usize: quote! { 0usize },
         }
     }
 
-    fn from_unnamed_fields(fields: &FieldsUnnamed) -> Self {
+    fn from_unnamed_fields(fields: &FieldsUnnamed, krate: &TokenStream) -> Self {
         let mut params_from = quote! {};
         let mut into_usize = quote! { 0usize };
         let mut length = quote! { 1usize };
+        let mut length_consts = quote! {};
         for (i, field) in fields.unnamed.iter().enumerate() {
             let ty = &field.ty;
             let index_ident = Index::from(i);
-            let field_length = type_length(ty);
+            let field_length = type_length(krate, ty);
 
+            let field_into = type_into_usize(krate, ty, &quote! { self.#index_ident });
             into_usize = quote! {
-                (#into_usize + #length * ::enum_map::Enum::into_usize(self.#index_ident))
+                (#into_usize + #length * #field_into)
             };
 
-            params_from = quote! {
-                #params_from <#ty as ::enum_map::Enum>::from_usize(
-                    value / #length % #field_length
-                ),
-            };
+            let field_from =
+                type_from_usize(krate, ty, &quote! { value / #length % #field_length });
+            params_from = quote! { #params_from #field_from, };
 
-            length = quote! { (#length * #field_length) };
+            let end = format_ident!("FIELD_{}_END", i);
+            length_consts = quote! {
+                #length_consts
+                const #end: #krate::usize = #length * #field_length;
+            };
+            length = quote! { #end };
         }
 
         let from_usize = quote! { Self(#params_from) };
         Self {
             length,
+            length_consts,
             from_usize,
             into_usize,
         }
     }
 
-    fn from_named_fields(fields: &FieldsNamed) -> Self {
+    fn from_named_fields(fields: &FieldsNamed, krate: &TokenStream) -> Self {
         let mut params_from = quote! {};
         let mut into_usize = quote! { 0usize };
         let mut length = quote! { 1usize };
-        for field in fields.named.iter() {
+        let mut length_consts = quote! {};
+        for (i, field) in fields.named.iter().enumerate() {
             let ty = &field.ty;
             let ident = field.ident.as_ref().unwrap();
-            let field_length = type_length(ty);
+            let field_length = type_length(krate, ty);
 
+            let field_into = type_into_usize(krate, ty, &quote! { self.#ident });
             into_usize = quote! {
-                (#into_usize + #length * ::enum_map::Enum::into_usize(self.#ident))
+                (#into_usize + #length * #field_into)
             };
 
-            params_from = quote! {
-                #params_from #ident: <#ty as ::enum_map::Enum>::from_usize(
-                    value / #length % #field_length
-                ),
-            };
+            let field_from =
+                type_from_usize(krate, ty, &quote! { value / #length % #field_length });
+            params_from = quote! { #params_from #ident: #field_from, };
 
-            length = quote! { (#field_length * #length) };
+            let end = format_ident!("FIELD_{}_END", i);
+            length_consts = quote! {
+                #length_consts
+                const #end: #krate::usize = #length * #field_length;
+            };
+            length = quote! { #end };
         }
 
         let from_usize = quote! { Self { #params_from } };
         Self {
             length,
+            length_consts,
             from_usize,
             into_usize,
         }
     }
 
-    fn finish(&self, name: &Ident) -> TokenStream {
+    fn finish(&self, name: &Ident, krate: &TokenStream) -> TokenStream {
         let length = &self.length;
+        let length_consts = &self.length_consts;
         let from_usize = &self.from_usize;
         let into_usize = &self.into_usize;
 
         quote! {
-            #[automatically_derived]
-            impl ::enum_map::Enum for #name {
-                const LENGTH: ::enum_map::usize = #length;
-
-                #[inline]
-                fn from_usize(value: ::enum_map::usize) -> Self {
-                    #from_usize
+            const _: () = {
+                #length_consts
+
+                #[automatically_derived]
+                impl #krate::Enum for #name {
+                    const LENGTH: #krate::usize = #length;
+
+                    #[inline]
+                    fn from_usize(value: #krate::usize) -> Self {
+                        #from_usize
+                    }
+
+                    #[inline]
+                    fn into_usize(self) -> #krate::usize {
+                        #into_usize
+                    }
                 }
 
-                #[inline]
-                fn into_usize(self) -> ::enum_map::usize {
-                    #into_usize
+                #[automatically_derived]
+                impl<V> #krate::EnumArray<V> for #name {
+                    type Array = [V; #length];
                 }
-            }
 
-            #[automatically_derived]
-            impl<V> ::enum_map::EnumArray<V> for #name {
-                type Array = [V; #length];
-            }
+                #[automatically_derived]
+                impl #krate::EnumSetArray for #name {
+                    type Words = [#krate::usize; #krate::word_count(#length)];
+                }
+            };
         }
     }
 }
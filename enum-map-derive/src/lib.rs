@@ -15,8 +15,9 @@ mod derive_enum;
 mod derive_struct;
 
 use proc_macro2::TokenStream;
-use quote::quote;
-use syn::{Data, DeriveInput, Type};
+use quote::{quote, quote_spanned};
+use syn::spanned::Spanned;
+use syn::{Attribute, Data, DeriveInput, Ident, Path, Type};
 
 /// Derive macro generating an implementation of trait `Enum`.
 ///
@@ -25,6 +26,11 @@ use syn::{Data, DeriveInput, Type};
 /// by `Enum::into_usize`, iterators of enum map as well as
 /// `EnumMap::as_slice` method.
 ///
+/// Explicit discriminants, if any, are ignored. Variants are always
+/// numbered densely from `0`, in declaration order, so an enum with
+/// discriminants `5` and `10` still uses indices `0` and `1`, and its enum
+/// map has a length of `2`.
+///
 /// # Examples
 ///
 /// ## Enums Without Payload
@@ -158,21 +164,222 @@ use syn::{Data, DeriveInput, Type};
 /// assert_eq!(Foo::from_usize(1), Foo(true, A::B, X::Y));
 /// assert_eq!(Foo::from_usize(4), Foo(false, A::D, X::Y));
 /// assert_eq!(Foo::from_usize(9), Foo(true, A::C, X::Z));
-#[proc_macro_derive(Enum)]
+/// ```
+///
+/// ## Generic Enums
+///
+/// Enums with generic parameters implement both `Enum` and `EnumArray`.
+/// Field types are required to implement `Enum` and `EnumArray`, so
+/// generic parameters don't need to be bounded by `Enum`.
+/// Their number of values isn't known in generic code, so they don't
+/// implement `EnumSetArray` and cannot be stored in an `EnumSet`.
+///
+/// ```
+/// use enum_map::{enum_map, Enum};
+///
+/// #[derive(Enum, Debug, PartialEq, Eq)]
+/// enum Slot<T> {
+///     Item(T),
+///     Empty,
+/// }
+///
+/// assert_eq!(Slot::<bool>::LENGTH, 3);
+/// assert_eq!(Slot::Item(true).into_usize(), 1);
+/// assert_eq!(Slot::<bool>::from_usize(2), Slot::Empty);
+///
+/// let map = enum_map! { Slot::Empty => 0, _ => 1 };
+/// assert_eq!(map[Slot::Item(false)], 1);
+/// assert_eq!(map.as_slice(), [1, 1, 0]);
+/// ```
+///
+/// # Overflow
+///
+/// The number of possible values is computed in a constant expression,
+/// so a type whose number of values doesn't fit in `usize` fails to
+/// compile instead of wrapping. As every index is smaller than `LENGTH`,
+/// `Enum::into_usize` and `Enum::from_usize` cannot overflow afterwards.
+///
+/// ```compile_fail
+/// use enum_map::Enum;
+///
+/// #[derive(Enum)]
+/// struct Huge(u8, u8, u8, u8, u8, u8, u8, u8, u8);
+///
+/// const LENGTH: usize = Huge::LENGTH;
+/// ```
+///
+/// # Default variant
+///
+/// `#[enum_map(default = Variant)]` implements `Default` for an enum,
+/// returning a given unit variant. This is opt-in, so it doesn't conflict
+/// with `#[derive(Default)]` for enums that don't use it.
+///
+/// ```
+/// use enum_map::Enum;
+///
+/// #[derive(Debug, Enum, PartialEq)]
+/// #[enum_map(default = Medium)]
+/// enum Level {
+///     Low,
+///     Medium,
+///     High,
+/// }
+///
+/// assert_eq!(Level::default(), Level::Medium);
+/// ```
+///
+/// Variants with fields cannot be used as a default.
+///
+/// ```compile_fail
+/// use enum_map::Enum;
+///
+/// #[derive(Enum)]
+/// #[enum_map(default = B)]
+/// enum Example {
+///     A,
+///     B(bool),
+/// }
+/// ```
+///
+/// # Crate path
+///
+/// The generated code refers to `::enum_map` crate. When `enum_map` is
+/// re-exported from another crate, use `#[enum_map(crate = path)]` to
+/// specify where it can be found.
+///
+/// ```
+/// mod facade {
+///     pub use enum_map;
+/// }
+///
+/// use facade::enum_map::Enum;
+///
+/// #[derive(Enum)]
+/// #[enum_map(crate = facade::enum_map)]
+/// enum Example {
+///     A,
+///     B,
+/// }
+///
+/// assert_eq!(Example::LENGTH, 2);
+/// ```
+///
+/// # Errors
+///
+/// Unions are not supported, and the error points at the `union` keyword.
+///
+/// ```compile_fail
+/// use enum_map::Enum;
+///
+/// #[derive(Enum)]
+/// union Union {
+///     a: u8,
+///     b: bool,
+/// }
+/// ```
+///
+/// Every field type needs to implement `Enum`, otherwise the error points
+/// at the type of an offending field.
+///
+/// ```compile_fail,E0277
+/// use enum_map::Enum;
+///
+/// struct NotEnum;
+///
+/// #[derive(Enum)]
+/// enum Example {
+///     A,
+///     B(NotEnum),
+/// }
+/// ```
+#[proc_macro_derive(Enum, attributes(enum_map))]
 pub fn derive_enum_map(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input: DeriveInput = syn::parse(input).unwrap();
+    let attributes = match Attributes::parse(&input.attrs) {
+        Ok(attributes) => attributes,
+        Err(error) => return error.to_compile_error().into(),
+    };
+    let krate = &attributes.krate;
 
     let result = match input.data {
-        Data::Enum(data_enum) => derive_enum::generate(input.ident, data_enum),
-        Data::Struct(data_struct) => derive_struct::generate(input.ident, data_struct),
-        _ => quote! { compile_error! {"#[derive(Enum)] is only defined for enums and structs"} },
+        Data::Enum(data_enum) => derive_enum::generate(
+            input.ident,
+            &input.generics,
+            data_enum,
+            krate,
+            attributes.default.as_ref(),
+        ),
+        Data::Struct(data_struct) => match &attributes.default {
+            Some(default) => {
+                syn::Error::new_spanned(default, "default variant can only be specified for enums")
+                    .to_compile_error()
+            }
+            None => derive_struct::generate(input.ident, data_struct, krate),
+        },
+        Data::Union(data_union) => syn::Error::new(
+            data_union.union_token.span,
+            "#[derive(Enum)] is only defined for enums and structs",
+        )
+        .to_compile_error(),
     };
 
     result.into()
 }
 
-fn type_length(ty: &Type) -> TokenStream {
-    quote! {
-        <#ty as ::enum_map::Enum>::LENGTH
+/// Returns the length of a field type.
+///
+/// Trait items are accessed with spans of field types, so that errors about
+/// a field type not implementing `Enum` point at the field.
+fn type_length(krate: &TokenStream, ty: &Type) -> TokenStream {
+    quote_spanned! {ty.span()=>
+        <#ty as #krate::Enum>::LENGTH
+    }
+}
+
+/// Converts an index into a value of a field type, spanned like [`type_length`].
+fn type_from_usize(krate: &TokenStream, ty: &Type, value: &TokenStream) -> TokenStream {
+    quote_spanned! {ty.span()=>
+        <#ty as #krate::Enum>::from_usize(#value)
+    }
+}
+
+/// Converts a value of a field type into an index, spanned like [`type_length`].
+fn type_into_usize(krate: &TokenStream, ty: &Type, value: &TokenStream) -> TokenStream {
+    quote_spanned! {ty.span()=>
+        <#ty as #krate::Enum>::into_usize(#value)
+    }
+}
+
+/// Container attributes, given with `#[enum_map(...)]`.
+struct Attributes {
+    /// Path to `enum_map` crate, given with `crate = path`.
+    krate: TokenStream,
+    /// Variant returned by generated `Default` implementation, given with `default = Variant`.
+    default: Option<Ident>,
+}
+
+impl Attributes {
+    fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut attributes = Self {
+            krate: quote! { ::enum_map },
+            default: None,
+        };
+        for attr in attrs {
+            if attr.path().is_ident("enum_map") {
+                attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("crate") {
+                        let path: Path = meta.value()?.parse()?;
+                        attributes.krate = quote! { #path };
+                        Ok(())
+                    } else if meta.path.is_ident("default") {
+                        attributes.default = Some(meta.value()?.parse()?);
+                        Ok(())
+                    } else {
+                        Err(meta.error("unsupported enum_map attribute"))
+                    }
+                })?;
+            }
+        }
+        Ok(attributes)
     }
 }
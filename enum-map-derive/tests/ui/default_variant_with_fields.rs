@@ -0,0 +1,14 @@
+// SPDX-FileCopyrightText: 2023 Konrad Borowski <konrad@borowski.pw>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use enum_map::Enum;
+
+#[derive(Enum)]
+#[enum_map(default = B)]
+enum Example {
+    A,
+    B(bool),
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+// SPDX-FileCopyrightText: 2023 Konrad Borowski <konrad@borowski.pw>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use enum_map::Enum;
+
+struct NotEnum;
+
+#[derive(Enum)]
+enum Example {
+    A,
+    B(bool, NotEnum),
+    C { field: NotEnum },
+}
+
+fn main() {}
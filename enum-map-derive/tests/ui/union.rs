@@ -0,0 +1,13 @@
+// SPDX-FileCopyrightText: 2023 Konrad Borowski <konrad@borowski.pw>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use enum_map::Enum;
+
+#[derive(Enum)]
+union Union {
+    a: u8,
+    b: bool,
+}
+
+fn main() {}
@@ -0,0 +1,32 @@
+// SPDX-FileCopyrightText: 2023 Konrad Borowski <konrad@borowski.pw>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{enum_map, EnumArray, EnumMap};
+use alloc::vec::Vec;
+
+impl<K: EnumArray<Vec<V>>, V> EnumMap<K, Vec<V>> {
+    /// Groups values by their keys, preserving order of values for each key.
+    ///
+    /// Requires crate feature `"alloc"`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::EnumMap;
+    ///
+    /// let grouped = EnumMap::collect_grouped([(true, 1), (false, 2), (true, 3)]);
+    /// assert_eq!(grouped[false], [2]);
+    /// assert_eq!(grouped[true], [1, 3]);
+    /// ```
+    pub fn collect_grouped<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+    {
+        let mut map = enum_map! { _ => Vec::new() };
+        for (key, value) in iter {
+            map[key].push(value);
+        }
+        map
+    }
+}
@@ -3,8 +3,42 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use crate::{enum_map, EnumArray, EnumMap};
-use arbitrary::{Arbitrary, Result, Unstructured};
+use crate::{enum_map, Enum, EnumArray, EnumMap};
+use arbitrary::{Arbitrary, Error, Result, Unstructured};
+
+/// Generates an arbitrary key of an enum.
+///
+/// This is useful in fuzz targets that need to pick a valid key, for
+/// instance to index an arbitrary `EnumMap`.
+///
+/// Requires crate feature `"arbitrary"`
+///
+/// # Errors
+///
+/// Returns [`Error::EmptyChoose`] if the enum has no variants.
+///
+/// # Examples
+///
+/// ```
+/// use arbitrary::Unstructured;
+/// use enum_map::{arbitrary_key, Enum};
+///
+/// #[derive(Debug, Enum, PartialEq)]
+/// enum Example {
+///     A,
+///     B,
+///     C,
+/// }
+///
+/// let mut u = Unstructured::new(&[2]);
+/// assert_eq!(arbitrary_key::<Example>(&mut u).unwrap(), Example::C);
+/// ```
+pub fn arbitrary_key<K: Enum>(u: &mut Unstructured) -> Result<K> {
+    if K::LENGTH == 0 {
+        return Err(Error::EmptyChoose);
+    }
+    Ok(K::from_usize(u.int_in_range(0..=K::LENGTH - 1)?))
+}
 
 /// Requires crate feature `"arbitrary"`
 impl<'a, K: EnumArray<V>, V: Arbitrary<'a>> Arbitrary<'a> for EnumMap<K, V> {
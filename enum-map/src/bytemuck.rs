@@ -0,0 +1,23 @@
+// SPDX-FileCopyrightText: 2023 Konrad Borowski <konrad@borowski.pw>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{EnumArray, EnumMap};
+use bytemuck::{Pod, Zeroable};
+
+// SAFETY: `EnumMap` is `repr(transparent)` over `K::Array`, so it is
+// zeroable whenever its storage is.
+/// Requires crate feature `"bytemuck"`
+unsafe impl<K: EnumArray<V>, V> Zeroable for EnumMap<K, V> where K::Array: Zeroable {}
+
+// SAFETY: `EnumMap` is `repr(transparent)` over `K::Array`, so it has the
+// same layout and bit validity as its storage. `K::Array` being `Pod`
+// implies it's `Copy`, which makes `EnumMap` `Copy` too.
+/// Requires crate feature `"bytemuck"`
+unsafe impl<K, V> Pod for EnumMap<K, V>
+where
+    K: EnumArray<V> + 'static,
+    V: 'static,
+    K::Array: Pod,
+{
+}
@@ -0,0 +1,53 @@
+// SPDX-FileCopyrightText: 2023 Konrad Borowski <konrad@borowski.pw>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{EnumArray, EnumMap};
+use core::fmt::{self, Display, Formatter};
+
+impl<K: EnumArray<V>, V> EnumMap<K, V> {
+    /// Returns an adapter implementing [`Display`] for an enum map, using
+    /// `Display` implementations of keys and values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::enum_map;
+    ///
+    /// let map = enum_map! { false => "no", true => "yes" };
+    /// assert_eq!(map.display().to_string(), "{false: no, true: yes}");
+    /// ```
+    #[inline]
+    pub fn display(&self) -> DisplayMap<'_, K, V> {
+        DisplayMap { map: self }
+    }
+}
+
+/// Helper struct for displaying an `EnumMap`.
+///
+/// This `struct` is created by the `display` method of `EnumMap`.
+/// See its documentation for more.
+pub struct DisplayMap<'a, K: EnumArray<V>, V> {
+    map: &'a EnumMap<K, V>,
+}
+
+impl<K: EnumArray<V>, V> Clone for DisplayMap<'_, K, V> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K: EnumArray<V>, V> Copy for DisplayMap<'_, K, V> {}
+
+impl<K: EnumArray<V> + Display, V: Display> Display for DisplayMap<'_, K, V> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("{")?;
+        for (index, (key, value)) in self.map.iter().enumerate() {
+            if index != 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{key}: {value}")?;
+        }
+        f.write_str("}")
+    }
+}
@@ -5,11 +5,18 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+use crate::internal::Array;
 use crate::{enum_map, EnumArray, EnumMap};
+use core::convert::TryFrom;
 use core::fmt::{self, Debug, Formatter};
 use core::hash::{Hash, Hasher};
-use core::iter::{Extend, FromIterator};
-use core::ops::{Index, IndexMut};
+use core::iter::{Extend, FromIterator, Sum};
+use core::mem::ManuallyDrop;
+use core::ops::{
+    Add, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Index, IndexMut, Mul, Neg,
+    Not, Sub,
+};
+use core::ptr;
 
 impl<K: EnumArray<V> + Debug, V: Debug> Debug for EnumMap<K, V> {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
@@ -35,6 +42,21 @@ where
     }
 }
 
+/// Clones values into the map. Unlike the implementation for `(&K, &V)`,
+/// which copies both keys and values, this allows extending from
+/// iterators like [`EnumMap::iter`] when `V` is [`Clone`] but not [`Copy`].
+impl<'a, K, V> Extend<(K, &'a V)> for EnumMap<K, V>
+where
+    K: EnumArray<V>,
+    V: Clone,
+{
+    fn extend<I: IntoIterator<Item = (K, &'a V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self[key].clone_from(value);
+        }
+    }
+}
+
 impl<K, V> FromIterator<(K, V)> for EnumMap<K, V>
 where
     Self: Default,
@@ -88,6 +110,27 @@ impl<K: EnumArray<V>, V: PartialEq> PartialEq for EnumMap<K, V> {
 
 impl<K: EnumArray<V>, V: Eq> Eq for EnumMap<K, V> {}
 
+/// Converts an array into an enum map, returning the array back as an
+/// error if its length doesn't match the number of variants of `K`.
+///
+/// `From` cannot be implemented instead, as the array length cannot be tied
+/// to the length of an enum at type level. Use [`EnumMap::from_array`] when
+/// the exact array type is known.
+impl<K: EnumArray<V>, V, const N: usize> TryFrom<[V; N]> for EnumMap<K, V> {
+    type Error = [V; N];
+
+    fn try_from(array: [V; N]) -> Result<Self, [V; N]> {
+        if N != K::Array::LENGTH {
+            return Err(array);
+        }
+        let array = ManuallyDrop::new(array);
+        // SAFETY: `K::Array` is an array of `V` with the same length.
+        Ok(EnumMap::from_array(unsafe {
+            ptr::read(array.as_ptr().cast())
+        }))
+    }
+}
+
 impl<K: EnumArray<V>, V: Hash> Hash for EnumMap<K, V> {
     #[inline]
     fn hash<H: Hasher>(&self, state: &mut H) {
@@ -113,3 +156,118 @@ impl<K: EnumArray<V>, V: Ord> Ord for EnumMap<K, V> {
         self.as_slice().cmp(other.as_slice())
     }
 }
+
+/// Sums enum maps element-wise.
+///
+/// # Examples
+///
+/// ```
+/// use enum_map::{enum_map, EnumMap};
+///
+/// let maps = vec![enum_map! { false => 1, true => 2 }, enum_map! { false => 3, true => 4 }];
+/// let total: EnumMap<_, i32> = maps.into_iter().sum();
+/// assert_eq!(total, enum_map! { false => 4, true => 6 });
+/// ```
+impl<K: EnumArray<V>, V: Add<Output = V> + Default> Sum for EnumMap<K, V> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), |total, map| {
+            let mut values = map.into_values();
+            total.map(|_, total| total + values.next().unwrap())
+        })
+    }
+}
+
+macro_rules! bitwise_operator {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident) => {
+        impl<K: EnumArray<bool>> $assign_trait for EnumMap<K, bool> {
+            #[inline]
+            fn $assign_method(&mut self, rhs: Self) {
+                for (value, rhs) in self.as_mut_slice().iter_mut().zip(rhs.as_slice()) {
+                    value.$assign_method(rhs);
+                }
+            }
+        }
+
+        impl<K: EnumArray<bool>> $trait for EnumMap<K, bool> {
+            type Output = Self;
+
+            #[inline]
+            fn $method(mut self, rhs: Self) -> Self {
+                self.$assign_method(rhs);
+                self
+            }
+        }
+    };
+}
+
+bitwise_operator!(BitAnd, bitand, BitAndAssign, bitand_assign);
+bitwise_operator!(BitOr, bitor, BitOrAssign, bitor_assign);
+bitwise_operator!(BitXor, bitxor, BitXorAssign, bitxor_assign);
+
+impl<K: EnumArray<bool>> Not for EnumMap<K, bool> {
+    type Output = Self;
+
+    #[inline]
+    fn not(mut self) -> Self {
+        for value in self.as_mut_slice() {
+            *value = !*value;
+        }
+        self
+    }
+}
+
+/// Negates every value.
+///
+/// # Examples
+///
+/// ```
+/// use enum_map::enum_map;
+///
+/// let map = enum_map! { false => 1, true => -2 };
+/// assert_eq!(-map, enum_map! { false => -1, true => 2 });
+/// ```
+impl<K: EnumArray<V>, V: Neg<Output = V>> Neg for EnumMap<K, V> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        self.map(|_, value| -value)
+    }
+}
+
+/// A value applied to every value of an enum map by arithmetic operators.
+///
+/// Wrapping the right-hand side makes it explicit that the operation
+/// applies the same value to every value of a map.
+///
+/// # Examples
+///
+/// ```
+/// use enum_map::{enum_map, Scalar};
+///
+/// let map = enum_map! { false => 1, true => 2 };
+/// assert_eq!(map * Scalar(3), enum_map! { false => 3, true => 6 });
+/// assert_eq!(map + Scalar(1), enum_map! { false => 2, true => 3 });
+/// assert_eq!(map - Scalar(1), enum_map! { false => 0, true => 1 });
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Scalar<T>(pub T);
+
+macro_rules! scalar_operator {
+    ($trait:ident, $method:ident) => {
+        impl<K: EnumArray<V>, V: $trait<S, Output = V>, S: Copy> $trait<Scalar<S>>
+            for EnumMap<K, V>
+        {
+            type Output = Self;
+
+            #[inline]
+            fn $method(self, rhs: Scalar<S>) -> Self {
+                self.map(|_, value| value.$method(rhs.0))
+            }
+        }
+    };
+}
+
+scalar_operator!(Add, add);
+scalar_operator!(Sub, sub);
+scalar_operator!(Mul, mul);
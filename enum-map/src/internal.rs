@@ -6,6 +6,8 @@
 
 use core::cmp::Ordering;
 use core::convert::Infallible;
+use core::marker::PhantomData;
+use core::ops::ControlFlow;
 
 /// Enum mapping type.
 ///
@@ -16,12 +18,63 @@ use core::convert::Infallible;
 /// it like one, as array of `u8` keys is a relatively common pattern.
 pub trait Enum: Sized {
     /// Length of the enum.
+    ///
+    /// `#[derive(Enum)]` sets this to a concrete value, so it can be used as
+    /// an array length for a specific enum, like `[u8; Example::LENGTH]`.
+    /// In generic code, stable Rust doesn't allow array lengths to depend on
+    /// generic parameters, so `[T; K::LENGTH]` doesn't compile there. Use
+    /// [`EnumArray::Array`] or [`EnumMap`](crate::EnumMap) for storage sized
+    /// by a generic enum instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::Enum;
+    ///
+    /// #[derive(Enum)]
+    /// enum Example {
+    ///     A,
+    ///     B,
+    ///     C,
+    /// }
+    ///
+    /// fn len<K: Enum>() -> usize {
+    ///     K::LENGTH
+    /// }
+    ///
+    /// let buffer = [0u8; Example::LENGTH];
+    /// assert_eq!(buffer.len(), len::<Example>());
+    /// ```
     const LENGTH: usize;
 
     /// Takes an usize, and returns an element matching `into_usize` function.
     fn from_usize(value: usize) -> Self;
     /// Returns an unique identifier for a value within range of `0..Array::LENGTH`.
     fn into_usize(self) -> usize;
+
+    /// Takes an usize, and returns an element matching `into_usize` function,
+    /// or `None` if the value is out of range.
+    ///
+    /// The default implementation checks `value` against `LENGTH` before
+    /// calling `from_usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::Enum;
+    ///
+    /// assert_eq!(bool::try_from_usize(1), Some(true));
+    /// assert_eq!(bool::try_from_usize(2), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn try_from_usize(value: usize) -> Option<Self> {
+        if value < Self::LENGTH {
+            Some(Self::from_usize(value))
+        } else {
+            None
+        }
+    }
 }
 
 /// Trait associating enum with an array.
@@ -34,6 +87,31 @@ pub trait EnumArray<V>: Enum {
     type Array: Array<V>;
 }
 
+/// Trait associating enum with words storing a set of its values.
+///
+/// [`EnumSet`](crate::EnumSet) stores one bit for each value of an enum,
+/// packed into `usize` words. For the same reason as with `EnumArray`, the
+/// array of words is provided by a trait. Its length must be
+/// `(LENGTH + usize::BITS - 1) / usize::BITS`.
+///
+/// This trait is implemented by `#[derive(Enum)]` for types without generic
+/// parameters.
+pub trait EnumSetArray: Enum {
+    /// Words storing bits of an enum set.
+    type Words: Array<usize>;
+}
+
+/// Trait listing every value of an enum in a constant.
+///
+/// Trait methods cannot be `const fn`, so `const_enum_map!` reads keys from
+/// this constant instead of calling `Enum::from_usize`. It's implemented by
+/// `#[derive(Enum)]` for enums without fields.
+#[doc(hidden)]
+pub trait ConstEnum: Enum + 'static {
+    /// Every value, ordered by index.
+    const VALUES: &'static [Self];
+}
+
 /// Array for enum-map storage.
 ///
 /// This trait is inteded for primitive array types (with fixed length).
@@ -51,12 +129,57 @@ unsafe impl<V, const N: usize> Array<V> for [V; N] {
     const LENGTH: usize = N;
 }
 
+/// Array storing values of `A` followed by values of `B`.
+///
+/// Array lengths cannot depend on generic parameters, so `#[derive(Enum)]`
+/// on a generic enum uses this to store values of each variant one after
+/// another.
+#[doc(hidden)]
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct SumArray<A, B> {
+    first: A,
+    second: B,
+}
+
+// SAFETY: Both arrays store values of `V`, so their size is a multiple of
+// alignment of `V`, and `second` immediately follows `first` without padding.
+unsafe impl<V, A: Array<V>, B: Array<V>> Array<V> for SumArray<A, B> {
+    const LENGTH: usize = A::LENGTH + B::LENGTH;
+}
+
+/// Array of `A`, where `A` is an array of `I`, which is an array of values.
+///
+/// This is used by `#[derive(Enum)]` on a generic enum to store values of
+/// a variant with fields, one nested array for each field.
+#[doc(hidden)]
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct ProductArray<A, I> {
+    array: A,
+    _phantom: PhantomData<I>,
+}
+
+// SAFETY: Arrays don't have padding between elements, so an array of `I`
+// stores `A::LENGTH * I::LENGTH` values of `V` one after another.
+unsafe impl<V, I: Array<V>, A: Array<I>> Array<V> for ProductArray<A, I> {
+    const LENGTH: usize = A::LENGTH * I::LENGTH;
+}
+
 #[doc(hidden)]
 #[inline]
 pub fn out_of_bounds() -> ! {
     panic!("index out of range for Enum::from_usize");
 }
 
+/// Returns the number of `usize` words needed to store `length` bits.
+#[doc(hidden)]
+#[must_use]
+pub const fn word_count(length: usize) -> usize {
+    let bits = usize::BITS as usize;
+    length / bits + (length % bits != 0) as usize
+}
+
 impl Enum for bool {
     const LENGTH: usize = 2;
 
@@ -72,12 +195,28 @@ impl Enum for bool {
     fn into_usize(self) -> usize {
         usize::from(self)
     }
+    #[inline]
+    fn try_from_usize(value: usize) -> Option<Self> {
+        match value {
+            0 => Some(false),
+            1 => Some(true),
+            _ => None,
+        }
+    }
 }
 
 impl<T> EnumArray<T> for bool {
     type Array = [T; Self::LENGTH];
 }
 
+impl EnumSetArray for bool {
+    type Words = [usize; word_count(Self::LENGTH)];
+}
+
+impl ConstEnum for bool {
+    const VALUES: &'static [Self] = &[false, true];
+}
+
 impl Enum for () {
     const LENGTH: usize = 1;
 
@@ -98,6 +237,14 @@ impl<T> EnumArray<T> for () {
     type Array = [T; Self::LENGTH];
 }
 
+impl EnumSetArray for () {
+    type Words = [usize; word_count(Self::LENGTH)];
+}
+
+impl ConstEnum for () {
+    const VALUES: &'static [Self] = &[()];
+}
+
 impl Enum for u8 {
     const LENGTH: usize = 256;
 
@@ -109,12 +256,35 @@ impl Enum for u8 {
     fn into_usize(self) -> usize {
         usize::from(self)
     }
+    #[inline]
+    fn try_from_usize(value: usize) -> Option<Self> {
+        value.try_into().ok()
+    }
 }
 
 impl<T> EnumArray<T> for u8 {
     type Array = [T; Self::LENGTH];
 }
 
+impl EnumSetArray for u8 {
+    type Words = [usize; word_count(Self::LENGTH)];
+}
+
+impl ConstEnum for u8 {
+    const VALUES: &'static [Self] = &{
+        let mut values = [0; Self::LENGTH];
+        let mut index = 0;
+        while index < Self::LENGTH {
+            #[allow(clippy::cast_possible_truncation)]
+            {
+                values[index] = index as u8;
+            }
+            index += 1;
+        }
+        values
+    };
+}
+
 impl Enum for Infallible {
     const LENGTH: usize = 0;
 
@@ -132,6 +302,14 @@ impl<T> EnumArray<T> for Infallible {
     type Array = [T; Self::LENGTH];
 }
 
+impl EnumSetArray for Infallible {
+    type Words = [usize; word_count(Self::LENGTH)];
+}
+
+impl ConstEnum for Infallible {
+    const VALUES: &'static [Self] = &[];
+}
+
 impl Enum for Ordering {
     const LENGTH: usize = 3;
 
@@ -152,8 +330,211 @@ impl Enum for Ordering {
             Ordering::Greater => 2,
         }
     }
+    #[inline]
+    fn try_from_usize(value: usize) -> Option<Self> {
+        match value {
+            0 => Some(Ordering::Less),
+            1 => Some(Ordering::Equal),
+            2 => Some(Ordering::Greater),
+            _ => None,
+        }
+    }
 }
 
 impl<T> EnumArray<T> for Ordering {
     type Array = [T; Self::LENGTH];
 }
+
+impl EnumSetArray for Ordering {
+    type Words = [usize; word_count(Self::LENGTH)];
+}
+
+impl ConstEnum for Ordering {
+    const VALUES: &'static [Self] = &[Ordering::Less, Ordering::Equal, Ordering::Greater];
+}
+
+/// `Continue` values come first, followed by `Break` values.
+///
+/// The length of an enum with fields cannot be used as an array length in
+/// generic code, so `EnumArray` (needed to use it as an `EnumMap` key) and
+/// `EnumSetArray` are only implemented for `ControlFlow<(), ()>`.
+impl<B: Enum, C: Enum> Enum for ControlFlow<B, C> {
+    const LENGTH: usize = C::LENGTH + B::LENGTH;
+
+    #[inline]
+    fn from_usize(value: usize) -> Self {
+        if value < C::LENGTH {
+            ControlFlow::Continue(C::from_usize(value))
+        } else {
+            ControlFlow::Break(B::from_usize(value - C::LENGTH))
+        }
+    }
+    #[inline]
+    fn into_usize(self) -> usize {
+        match self {
+            ControlFlow::Continue(value) => value.into_usize(),
+            ControlFlow::Break(value) => C::LENGTH + value.into_usize(),
+        }
+    }
+}
+
+impl<T> EnumArray<T> for ControlFlow<(), ()> {
+    type Array = [T; Self::LENGTH];
+}
+
+impl EnumSetArray for ControlFlow<(), ()> {
+    type Words = [usize; word_count(Self::LENGTH)];
+}
+
+impl<A: Enum> Enum for (A,) {
+    const LENGTH: usize = A::LENGTH;
+
+    #[inline]
+    fn from_usize(value: usize) -> Self {
+        (A::from_usize(value),)
+    }
+    #[inline]
+    fn into_usize(self) -> usize {
+        self.0.into_usize()
+    }
+}
+
+impl<T, A: EnumArray<T>> EnumArray<T> for (A,) {
+    type Array = A::Array;
+}
+
+impl<A: EnumSetArray> EnumSetArray for (A,) {
+    type Words = A::Words;
+}
+
+/// Treats the tuple like a struct with two fields, so the first element is
+/// the least significant.
+///
+/// Using `LENGTH` fails to compile when the number of values doesn't fit in
+/// `usize`.
+impl<A: Enum, B: Enum> Enum for (A, B) {
+    const LENGTH: usize = A::LENGTH * B::LENGTH;
+
+    #[inline]
+    fn from_usize(value: usize) -> Self {
+        if value >= <Self as Enum>::LENGTH {
+            out_of_bounds();
+        }
+        (
+            A::from_usize(value % A::LENGTH),
+            B::from_usize(value / A::LENGTH),
+        )
+    }
+    #[inline]
+    fn into_usize(self) -> usize {
+        self.0.into_usize() + A::LENGTH * self.1.into_usize()
+    }
+}
+
+impl<T, A: EnumArray<T>, B: EnumArray<A::Array>> EnumArray<T> for (A, B) {
+    type Array = ProductArray<<B as EnumArray<A::Array>>::Array, A::Array>;
+}
+
+/// Treats the array as a little-endian binary number, so the first element
+/// is the least significant bit.
+///
+/// The length of `[bool; N]` cannot be used as an array length in generic
+/// code, so `EnumArray` (needed to use it as an `EnumMap` key) and
+/// `EnumSetArray` are only implemented for `N` up to 8.
+///
+/// Using `LENGTH` fails to compile when the number of values doesn't fit in
+/// `usize`.
+///
+/// ```compile_fail
+/// use enum_map::Enum;
+///
+/// const LENGTH: usize = <[bool; 64]>::LENGTH;
+/// ```
+impl<const N: usize> Enum for [bool; N] {
+    const LENGTH: usize = {
+        assert!(N < usize::BITS as usize, "[bool; N] has too many values");
+        1 << N
+    };
+
+    #[inline]
+    fn from_usize(value: usize) -> Self {
+        if value >= <Self as Enum>::LENGTH {
+            out_of_bounds();
+        }
+        let mut bits = [false; N];
+        for (i, bit) in bits.iter_mut().enumerate() {
+            *bit = value & (1 << i) != 0;
+        }
+        bits
+    }
+    #[inline]
+    fn into_usize(self) -> usize {
+        self.iter()
+            .rev()
+            .fold(0, |value, &bit| (value << 1) | usize::from(bit))
+    }
+}
+
+macro_rules! bool_array_enum_array {
+    ($($n:literal)*) => {
+        $(
+            impl<T> EnumArray<T> for [bool; $n] {
+                type Array = [T; <Self as Enum>::LENGTH];
+            }
+
+            impl EnumSetArray for [bool; $n] {
+                type Words = [usize; word_count(<Self as Enum>::LENGTH)];
+            }
+        )*
+    };
+}
+
+bool_array_enum_array!(0 1 2 3 4 5 6 7 8);
+
+/// Maps every Unicode scalar value to an index, skipping the surrogate range.
+///
+/// Keep in mind that there are 1,112,064 possible `char` values, so an
+/// enum map keyed by `char` is enormous: even `EnumMap<char, u8>` takes over
+/// a megabyte, and `EnumMap<char, usize>` on a 64-bit platform takes over
+/// 8 MiB, which is likely to overflow the stack unless boxed.
+///
+/// Requires crate feature `"char-enum"`
+#[cfg(feature = "char-enum")]
+impl Enum for char {
+    const LENGTH: usize = 0x11_0000 - 0x800;
+
+    #[inline]
+    fn from_usize(value: usize) -> Self {
+        if value >= Self::LENGTH {
+            out_of_bounds();
+        }
+        let value = if value >= 0xD800 {
+            value + 0x800
+        } else {
+            value
+        };
+        u32::try_from(value)
+            .ok()
+            .and_then(char::from_u32)
+            .unwrap_or_else(|| out_of_bounds())
+    }
+    #[inline]
+    fn into_usize(self) -> usize {
+        let value = self as usize;
+        if value >= 0xE000 {
+            value - 0x800
+        } else {
+            value
+        }
+    }
+}
+
+#[cfg(feature = "char-enum")]
+impl<T> EnumArray<T> for char {
+    type Array = [T; Self::LENGTH];
+}
+
+#[cfg(feature = "char-enum")]
+impl EnumSetArray for char {
+    type Words = [usize; word_count(Self::LENGTH)];
+}
@@ -7,6 +7,7 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
 use crate::{EnumArray, EnumMap};
+use core::fmt::{self, Debug, Formatter};
 use core::iter::{Enumerate, FusedIterator};
 use core::marker::PhantomData;
 use core::mem::ManuallyDrop;
@@ -17,7 +18,8 @@ use core::slice;
 /// Immutable enum map iterator
 ///
 /// This struct is created by `iter` method or `into_iter` on a reference
-/// to `EnumMap`.
+/// to `EnumMap`. Keys are visited in index order, as described in
+/// [`EnumMap::iter`].
 ///
 /// # Examples
 ///
@@ -46,7 +48,7 @@ pub struct Iter<'a, K, V: 'a> {
     iterator: Enumerate<slice::Iter<'a, V>>,
 }
 
-impl<'a, K: EnumArray<V>, V> Clone for Iter<'a, K, V> {
+impl<'a, K, V> Clone for Iter<'a, K, V> {
     fn clone(&self) -> Self {
         Iter {
             _phantom: PhantomData,
@@ -107,7 +109,8 @@ impl<'a, K: EnumArray<V>, V> IntoIterator for &'a EnumMap<K, V> {
 /// Mutable map iterator
 ///
 /// This struct is created by `iter_mut` method or `into_iter` on a mutable
-/// reference to `EnumMap`.
+/// reference to `EnumMap`. Keys are visited in index order, as described in
+/// [`EnumMap::iter`].
 ///
 /// # Examples
 ///
@@ -131,15 +134,17 @@ impl<'a, K: EnumArray<V>, V> IntoIterator for &'a EnumMap<K, V> {
 pub struct IterMut<'a, K, V: 'a> {
     _phantom: PhantomData<fn() -> K>,
     iterator: Enumerate<slice::IterMut<'a, V>>,
+    offset: usize,
 }
 
 impl<'a, K: EnumArray<V>, V> Iterator for IterMut<'a, K, V> {
     type Item = (K, &'a mut V);
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
+        let offset = self.offset;
         self.iterator
             .next()
-            .map(|(index, item)| (K::from_usize(index), item))
+            .map(|(index, item)| (K::from_usize(offset + index), item))
     }
 
     #[inline]
@@ -151,8 +156,9 @@ impl<'a, K: EnumArray<V>, V> Iterator for IterMut<'a, K, V> {
     where
         F: FnMut(B, Self::Item) -> B,
     {
+        let offset = self.offset;
         self.iterator
-            .map(|(index, item)| (K::from_usize(index), item))
+            .map(|(index, item)| (K::from_usize(offset + index), item))
             .fold(init, f)
     }
 }
@@ -160,9 +166,10 @@ impl<'a, K: EnumArray<V>, V> Iterator for IterMut<'a, K, V> {
 impl<'a, K: EnumArray<V>, V> DoubleEndedIterator for IterMut<'a, K, V> {
     #[inline]
     fn next_back(&mut self) -> Option<Self::Item> {
+        let offset = self.offset;
         self.iterator
             .next_back()
-            .map(|(index, item)| (K::from_usize(index), item))
+            .map(|(index, item)| (K::from_usize(offset + index), item))
     }
 }
 
@@ -178,13 +185,15 @@ impl<'a, K: EnumArray<V>, V> IntoIterator for &'a mut EnumMap<K, V> {
         IterMut {
             _phantom: PhantomData,
             iterator: self.as_mut_slice().iter_mut().enumerate(),
+            offset: 0,
         }
     }
 }
 
 /// A map iterator that moves out of map.
 ///
-/// This struct is created by `into_iter` on `EnumMap`.
+/// This struct is created by `into_iter` on `EnumMap`. Keys are visited in
+/// index order, as described in [`EnumMap::iter`].
 ///
 /// # Examples
 ///
@@ -220,6 +229,21 @@ impl<K: EnumArray<V>, V> Iterator for IntoIter<K, V> {
     fn size_hint(&self) -> (usize, Option<usize>) {
         self.alive.size_hint()
     }
+
+    fn nth(&mut self, n: usize) -> Option<(K, V)> {
+        let start = self.alive.start;
+        let end = start.saturating_add(n).min(self.alive.end);
+        // Skipped values are removed from the alive range before dropping
+        // them, so that they aren't dropped again if a destructor panics.
+        self.alive.start = end;
+        // SAFETY: Values in `start..end` were in the alive range, so they are
+        // initialized, and they are no longer in it, so neither `next` nor
+        // the `Drop` impl will read or drop them again.
+        unsafe {
+            ptr::drop_in_place(&mut self.map.as_mut_slice()[start..end]);
+        }
+        self.next()
+    }
 }
 
 impl<K: EnumArray<V>, V> DoubleEndedIterator for IntoIter<K, V> {
@@ -258,6 +282,48 @@ impl<K: EnumArray<V>, V> IntoIterator for EnumMap<K, V> {
 }
 
 impl<K: EnumArray<V>, V> EnumMap<K, V> {
+    /// Splits the map into two mutable iterators, the first one visiting
+    /// keys before `key`, and the second one visiting `key` and keys after
+    /// it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::{enum_map, Enum};
+    ///
+    /// #[derive(Debug, Enum, PartialEq)]
+    /// enum State {
+    ///     Idle,
+    ///     Running,
+    ///     Done,
+    /// }
+    ///
+    /// let mut map = enum_map! { _ => 0 };
+    /// let (before, after) = map.split_iter_mut(State::Running);
+    /// assert_eq!(before.map(|(key, _)| key).collect::<Vec<_>>(), [State::Idle]);
+    /// for (key, value) in after {
+    ///     *value = key.into_usize();
+    /// }
+    /// assert_eq!(map.as_slice(), [0, 1, 2]);
+    /// ```
+    #[inline]
+    pub fn split_iter_mut(&mut self, key: K) -> (IterMut<'_, K, V>, IterMut<'_, K, V>) {
+        let mid = key.into_usize();
+        let (before, after) = self.as_mut_slice().split_at_mut(mid);
+        (
+            IterMut {
+                _phantom: PhantomData,
+                iterator: before.iter_mut().enumerate(),
+                offset: 0,
+            },
+            IterMut {
+                _phantom: PhantomData,
+                iterator: after.iter_mut().enumerate(),
+                offset: mid,
+            },
+        )
+    }
+
     /// An iterator visiting all values. The iterator type is `&V`.
     ///
     /// # Examples
@@ -327,6 +393,12 @@ impl<'a, V> Clone for Values<'a, V> {
     }
 }
 
+impl<V: Debug> Debug for Values<'_, V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Values").field(&self.0.as_slice()).finish()
+    }
+}
+
 impl<'a, V: 'a> Iterator for Values<'a, V> {
     type Item = &'a V;
     #[inline]
@@ -357,6 +429,14 @@ impl<'a, V: 'a> FusedIterator for Values<'a, V> {}
 /// See its documentation for more.
 pub struct ValuesMut<'a, V: 'a>(slice::IterMut<'a, V>);
 
+impl<V: Debug> Debug for ValuesMut<'_, V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ValuesMut")
+            .field(&self.0.as_slice())
+            .finish()
+    }
+}
+
 impl<'a, V: 'a> Iterator for ValuesMut<'a, V> {
     type Item = &'a mut V;
     #[inline]
@@ -43,16 +43,48 @@
 #![deny(missing_docs)]
 #![warn(clippy::pedantic)]
 
+#[cfg(any(feature = "alloc", feature = "proptest", feature = "rayon"))]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+mod alloc_impls;
 #[cfg(feature = "arbitrary")]
 mod arbitrary;
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
+mod display;
 mod enum_map_impls;
 mod internal;
 mod iter;
+#[cfg(feature = "proptest")]
+mod proptest;
+#[cfg(feature = "rand")]
+mod rand;
+#[cfg(feature = "rayon")]
+mod rayon;
 #[cfg(feature = "serde")]
 mod serde;
+mod set;
+#[cfg(feature = "alloc")]
+mod sort;
+#[cfg(feature = "std")]
+mod std_impls;
 
+#[cfg(feature = "arbitrary")]
+pub use self::arbitrary::arbitrary_key;
+pub use self::display::DisplayMap;
+pub use self::enum_map_impls::Scalar;
+#[cfg(feature = "proptest")]
+pub use self::proptest::enum_map_strategy;
+#[cfg(feature = "serde")]
+pub use self::serde::{int_keys, AsSeq, WithDefaults};
+#[cfg(feature = "std")]
+pub use self::std_impls::MissingKeyError;
+use core::cmp::Ordering;
+use core::iter::{Product, Sum};
 #[doc(hidden)]
 pub use core::mem::{self, ManuallyDrop, MaybeUninit};
+use core::ops::Range;
 #[doc(hidden)]
 pub use core::primitive::usize;
 use core::slice;
@@ -60,11 +92,12 @@ use core::slice;
 // unreachable needs to be exported for compatibility with older versions of enum-map-derive
 pub use core::{panic, ptr, unreachable};
 pub use enum_map_derive::Enum;
-#[doc(hidden)]
-pub use internal::out_of_bounds;
 use internal::Array;
-pub use internal::{Enum, EnumArray};
+#[doc(hidden)]
+pub use internal::{out_of_bounds, word_count, ConstEnum, ProductArray, SumArray};
+pub use internal::{Enum, EnumArray, EnumSetArray};
 pub use iter::{IntoIter, IntoValues, Iter, IterMut, Values, ValuesMut};
+pub use set::{EnumSet, EnumSetIter};
 
 // SAFETY: initialized needs to represent number of initialized elements
 #[doc(hidden)]
@@ -139,6 +172,20 @@ where
     pub guard: Guard<'a, K, V>,
 }
 
+#[doc(hidden)]
+/// # Safety
+///
+/// Every element of the array needs to be initialized.
+pub const unsafe fn assume_init_array<V: Copy, const N: usize>(
+    array: [MaybeUninit<V>; N],
+) -> [V; N] {
+    union Transmute<V: Copy, const N: usize> {
+        uninit: [MaybeUninit<V>; N],
+        init: [V; N],
+    }
+    Transmute { uninit: array }.init
+}
+
 /// Enum map constructor.
 ///
 /// This macro allows to create a new enum map in a type safe way. It takes
@@ -146,9 +193,11 @@ where
 /// separated list of enum keys, or `_` to match all unmatched enum keys,
 /// while right side is a value.
 ///
-/// The iteration order when using this macro is not guaranteed to be
-/// consistent. Future releases of this crate may change it, and this is not
-/// considered to be a breaking change.
+/// The order in which this macro evaluates values for keys is not
+/// guaranteed to be consistent. Future releases of this crate may change
+/// it, and this is not considered to be a breaking change. This only
+/// affects side effects of value expressions, iterating over the created
+/// map always visits keys in order described in [`EnumMap::iter`].
 ///
 /// # Examples
 ///
@@ -173,6 +222,36 @@ where
 /// assert_eq!(enum_map[Example::C], 2);
 /// assert_eq!(enum_map[Example::D], 3);
 /// ```
+///
+/// Range patterns can be used with `u8` keys.
+///
+/// ```
+/// use enum_map::enum_map;
+///
+/// let enum_map = enum_map! { b'a'..=b'z' => true, _ => false };
+/// assert!(enum_map[b'q']);
+/// assert!(!enum_map[b'Q']);
+/// ```
+///
+/// Keys are matched with a `match` expression, so forgetting a key without
+/// providing a `_` arm is a "non-exhaustive patterns" error pointing at the
+/// macro invocation and listing the missing keys.
+///
+/// ```compile_fail,E0004
+/// use enum_map::{enum_map, Enum, EnumMap};
+///
+/// #[derive(Enum)]
+/// enum Example {
+///     A,
+///     B,
+///     C,
+/// }
+///
+/// let enum_map: EnumMap<Example, i32> = enum_map! {
+///     Example::A => 1,
+///     Example::B => 2,
+/// };
+/// ```
 #[macro_export]
 macro_rules! enum_map {
     {$($t:tt)*} => {{
@@ -205,6 +284,54 @@ macro_rules! enum_map {
     }};
 }
 
+/// Enum map constructor usable in constant expressions.
+///
+/// This macro works like [`enum_map!`], except that the key type needs to
+/// be provided before a `;` and the map can be used to initialize a `const`
+/// or a `static`. Values need to implement `Copy`, and the key type needs to
+/// be an enum without fields deriving [`Enum`], `bool`, `u8`, `()`,
+/// [`Ordering`](core::cmp::Ordering) or [`Infallible`](core::convert::Infallible).
+///
+/// # Examples
+///
+/// ```
+/// use enum_map::{const_enum_map, Enum, EnumMap};
+///
+/// #[derive(Enum)]
+/// enum Direction {
+///     North,
+///     East,
+///     South,
+///     West,
+/// }
+///
+/// static DELTA_Y: EnumMap<Direction, i32> = const_enum_map! {
+///     Direction;
+///     Direction::North => -1,
+///     Direction::South => 1,
+///     _ => 0,
+/// };
+/// assert_eq!(DELTA_Y[Direction::North], -1);
+/// assert_eq!(DELTA_Y[Direction::East], 0);
+/// ```
+#[macro_export]
+macro_rules! const_enum_map {
+    {$k:ty; $($t:tt)*} => {{
+        const LENGTH: $crate::usize = <$k as $crate::Enum>::LENGTH;
+        let mut array = [$crate::MaybeUninit::uninit(); LENGTH];
+        let mut index = 0;
+        #[allow(unreachable_code)]
+        while index < LENGTH {
+            array[index] = $crate::MaybeUninit::new(
+                match <$k as $crate::ConstEnum>::VALUES[index] { $($t)* }
+            );
+            index += 1;
+        }
+        // Safe because every element was initialized by the loop above.
+        $crate::EnumMap::<$k, _>::from_array(unsafe { $crate::assume_init_array(array) })
+    }};
+}
+
 /// An enum mapping.
 ///
 /// This internally uses an array which stores a value for each possible
@@ -218,6 +345,10 @@ macro_rules! enum_map {
 /// In particular, [reverse-complement in benchmark game] could be using `u8`
 /// as an enum.
 ///
+/// `EnumMap<K, V>` is `#[repr(transparent)]` over its array, so it's
+/// guaranteed to have the same layout as `[V; N]` where `N` is the number
+/// of variants of `K`.
+///
 /// # Examples
 ///
 /// ```
@@ -239,6 +370,19 @@ macro_rules! enum_map {
 ///
 /// [reverse-complement in benchmark game]:
 ///     http://benchmarksgame.alioth.debian.org/u64q/program.php?test=revcomp&lang=rust&id=2
+// Unlike `bytemuck` traits, `zerocopy` traits can only be implemented with
+// derives, which rely on `repr(transparent)` to check the layout.
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::Immutable,
+        zerocopy::IntoBytes,
+        zerocopy::KnownLayout,
+        zerocopy::Unaligned
+    )
+)]
+#[repr(transparent)]
 pub struct EnumMap<K: EnumArray<V>, V> {
     array: K::Array,
 }
@@ -269,6 +413,165 @@ impl<K: EnumArray<V>, V: Default> EnumMap<K, V> {
             *v = V::default();
         }
     }
+
+    /// Moves a value from one key to another, leaving a default value
+    /// in its place.
+    ///
+    /// The value previously stored under `to` is dropped. When `from`
+    /// and `to` are the same key, this does nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::{enum_map, Enum};
+    ///
+    /// #[derive(Enum)]
+    /// enum Example {
+    ///     A,
+    ///     B,
+    /// }
+    ///
+    /// let mut enum_map = enum_map! { Example::A => String::from("foo"), Example::B => String::new() };
+    /// enum_map.move_value(Example::A, Example::B);
+    /// assert_eq!(enum_map[Example::A], "");
+    /// assert_eq!(enum_map[Example::B], "foo");
+    /// ```
+    #[inline]
+    pub fn move_value(&mut self, from: K, to: K) {
+        let from = from.into_usize();
+        let to = to.into_usize();
+        if from != to {
+            let slice = self.as_mut_slice();
+            slice[to] = mem::take(&mut slice[from]);
+        }
+    }
+
+    /// Takes the value stored under a key, leaving a default value in its
+    /// place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::enum_map;
+    ///
+    /// let mut map = enum_map! { false => String::from("foo"), true => String::from("bar") };
+    /// assert_eq!(map.take(true), "bar");
+    /// assert_eq!(map[true], "");
+    /// ```
+    #[inline]
+    pub fn take(&mut self, key: K) -> V {
+        mem::take(&mut self[key])
+    }
+
+    /// Retains only the values for which the predicate returns `true`.
+    ///
+    /// An enum map always has a value for every key, so unlike
+    /// `HashMap::retain`, values for which `p` returns `false` are not
+    /// removed, but replaced with the default value. Keys are visited in
+    /// order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::{enum_map, Enum};
+    ///
+    /// #[derive(Debug, Enum)]
+    /// enum Example {
+    ///     A,
+    ///     B,
+    ///     C,
+    /// }
+    ///
+    /// let mut map = enum_map! { Example::A => 1, Example::B => 2, Example::C => 3 };
+    /// map.retain(|_, value| *value % 2 == 1);
+    /// assert_eq!(map.as_slice(), [1, 0, 3]);
+    /// ```
+    pub fn retain<P: FnMut(K, &mut V) -> bool>(&mut self, mut p: P) {
+        for (key, value) in self {
+            if !p(key, value) {
+                *value = V::default();
+            }
+        }
+    }
+
+    /// Returns `true` if every value is equal to the default value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::{Enum, EnumMap};
+    ///
+    /// #[derive(Enum)]
+    /// enum Example {
+    ///     A,
+    ///     B,
+    /// }
+    ///
+    /// let mut enum_map = EnumMap::<_, i32>::default();
+    /// assert!(enum_map.is_default());
+    /// enum_map[Example::B] = 1;
+    /// assert!(!enum_map.is_default());
+    /// ```
+    pub fn is_default(&self) -> bool
+    where
+        V: PartialEq,
+    {
+        let default = V::default();
+        self.as_slice().iter().all(|value| *value == default)
+    }
+}
+
+impl<K: EnumArray<MaybeUninit<V>>, V> EnumMap<K, MaybeUninit<V>> {
+    /// Creates an enum map with uninitialized values.
+    ///
+    /// Values can be initialized one by one, and then
+    /// [`assume_init`](Self::assume_init) converts the map into an enum map
+    /// of initialized values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::mem::MaybeUninit;
+    /// use enum_map::EnumMap;
+    ///
+    /// let mut map = EnumMap::<bool, MaybeUninit<String>>::uninit();
+    /// map[false].write(String::from("no"));
+    /// map[true].write(String::from("yes"));
+    /// let map = unsafe { map.assume_init() };
+    /// assert_eq!(map[true], "yes");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn uninit() -> Self {
+        enum_map! { _ => MaybeUninit::uninit() }
+    }
+
+    /// Converts an enum map of [`MaybeUninit`] values into an enum map of
+    /// initialized values.
+    ///
+    /// # Safety
+    ///
+    /// Every value needs to be initialized, like with
+    /// [`MaybeUninit::assume_init`].
+    ///
+    /// # Panics
+    ///
+    /// Panics when storage lengths for `V` and `MaybeUninit<V>` differ,
+    /// which can only happen with a manual `EnumArray` implementation.
+    pub unsafe fn assume_init(self) -> EnumMap<K, V>
+    where
+        K: EnumArray<V>,
+    {
+        assert_eq!(
+            <K as EnumArray<V>>::Array::LENGTH,
+            <K as EnumArray<MaybeUninit<V>>>::Array::LENGTH,
+        );
+        let map = ManuallyDrop::new(self);
+        // SAFETY: `MaybeUninit<V>` has the same layout as `V`, both arrays
+        // have the same length, and the caller guarantees every value is
+        // initialized.
+        EnumMap::from_array(ptr::read(map.as_slice().as_ptr().cast()))
+    }
 }
 
 #[allow(clippy::len_without_is_empty)]
@@ -284,6 +587,17 @@ impl<K: EnumArray<V>, V> EnumMap<K, V> {
     /// The iteration order is deterministic, and when using [macro@Enum] derive
     /// it will be the order in which enum variants are declared.
     ///
+    /// Keys are visited in the order of `Enum::from_usize(0)`,
+    /// `Enum::from_usize(1)` and so on, up to `Enum::LENGTH - 1`. This is
+    /// also the case for [`IterMut`] and [`IntoIter`], and is part of the
+    /// public API, so it won't change without a major version bump.
+    ///
+    /// Every key is created with `Enum::from_usize`, which for keys with
+    /// fields means constructing them again. When only some keys are needed,
+    /// iterate over [`indices`](Self::indices) with
+    /// [`get_index`](Self::get_index) and
+    /// [`key_from_index`](Self::key_from_index) instead.
+    ///
     /// # Examples
     ///
     /// ```
@@ -317,151 +631,1423 @@ impl<K: EnumArray<V>, V> EnumMap<K, V> {
         K::Array::LENGTH
     }
 
-    /// Swaps two indexes.
+    /// Returns a key corresponding to a given index, or `None` if the index
+    /// is out of range.
     ///
     /// # Examples
     ///
     /// ```
+    /// use core::cmp::Ordering;
     /// use enum_map::enum_map;
     ///
-    /// let mut map = enum_map! { false => 0, true => 1 };
-    /// map.swap(false, true);
-    /// assert_eq!(map[false], 1);
-    /// assert_eq!(map[true], 0);
+    /// let map = enum_map! { Ordering::Less => 'a', Ordering::Equal => 'b', Ordering::Greater => 'c' };
+    /// assert_eq!(map.key_from_index(1), Some(Ordering::Equal));
+    /// assert_eq!(map.key_from_index(3), None);
+    /// assert_eq!(map.key_from_index(2).map(|key| map[key]), Some('c'));
     /// ```
     #[inline]
-    pub fn swap(&mut self, a: K, b: K) {
-        self.as_mut_slice().swap(a.into_usize(), b.into_usize());
+    #[allow(clippy::unused_self)]
+    pub fn key_from_index(&self, index: usize) -> Option<K> {
+        if index < self.len() {
+            Some(K::from_usize(index))
+        } else {
+            None
+        }
     }
 
-    /// Consumes an enum map and returns the underlying array.
-    ///
-    /// The order of elements is deterministic, and when using [macro@Enum]
-    /// derive it will be the order in which enum variants are declared.
+    /// Returns the range of indices of values, which can be used with
+    /// [`get_index`](Self::get_index) to visit values without creating keys.
     ///
     /// # Examples
     ///
     /// ```
-    /// use enum_map::{enum_map, Enum};
-    ///
-    /// #[derive(Enum, PartialEq)]
-    /// enum E {
-    ///     A,
-    ///     B,
-    ///     C,
-    /// }
+    /// use enum_map::enum_map;
     ///
-    /// let map = enum_map! { E::A => 1, E::B => 2, E::C => 3};
-    /// assert_eq!(map.into_array(), [1, 2, 3]);
+    /// let map = enum_map! { false => 1, true => 2 };
+    /// let odd: Vec<_> = map
+    ///     .indices()
+    ///     .filter(|&index| map.get_index(index) == Some(&1))
+    ///     .filter_map(|index| map.key_from_index(index))
+    ///     .collect();
+    /// assert_eq!(odd, [false]);
     /// ```
-    pub fn into_array(self) -> K::Array {
-        self.array
+    #[inline]
+    pub fn indices(&self) -> Range<usize> {
+        0..self.len()
     }
 
-    /// Returns a reference to the underlying array.
-    ///
-    /// The order of elements is deterministic, and when using [macro@Enum]
-    /// derive it will be the order in which enum variants are declared.
+    /// Returns a reference to the value at a given index, or `None` if the
+    /// index is out of range.
     ///
     /// # Examples
     ///
     /// ```
-    /// use enum_map::{enum_map, Enum};
+    /// use enum_map::enum_map;
     ///
-    /// #[derive(Enum, PartialEq)]
-    /// enum E {
-    ///     A,
-    ///     B,
-    ///     C,
-    /// }
+    /// let map = enum_map! { false => 'a', true => 'b' };
+    /// assert_eq!(map.get_index(1), Some(&'b'));
+    /// assert_eq!(map.get_index(2), None);
+    /// ```
+    #[inline]
+    pub fn get_index(&self, index: usize) -> Option<&V> {
+        self.as_slice().get(index)
+    }
+
+    /// Returns a mutable reference to the value at a given index, or `None`
+    /// if the index is out of range.
+    ///
+    /// # Examples
     ///
-    /// let map = enum_map! { E::A => 1, E::B => 2, E::C => 3};
-    /// assert_eq!(map.as_array(), &[1, 2, 3]);
     /// ```
-    pub const fn as_array(&self) -> &K::Array {
-        &self.array
+    /// use enum_map::enum_map;
+    ///
+    /// let mut map = enum_map! { false => 'a', true => 'b' };
+    /// *map.get_index_mut(0).unwrap() = 'c';
+    /// assert_eq!(map[false], 'c');
+    /// assert_eq!(map.get_index_mut(2), None);
+    /// ```
+    #[inline]
+    pub fn get_index_mut(&mut self, index: usize) -> Option<&mut V> {
+        self.as_mut_slice().get_mut(index)
     }
 
-    /// Returns a mutable reference to the underlying array.
+    /// Returns a reference to the value for a key, converting it from
+    /// another type first.
     ///
-    /// The order of elements is deterministic, and when using [macro@Enum]
-    /// derive it will be the order in which enum variants are declared.
+    /// This is useful when keys are wrapped in newtypes. `Index` cannot be
+    /// implemented for every type convertible into a key, as that would break
+    /// type inference of keys like integer literals.
     ///
     /// # Examples
     ///
     /// ```
     /// use enum_map::{enum_map, Enum};
     ///
-    /// #[derive(Enum, PartialEq)]
-    /// enum E {
-    ///     A,
-    ///     B,
-    ///     C,
+    /// #[derive(Enum)]
+    /// enum Role {
+    ///     Attacker,
+    ///     Defender,
     /// }
     ///
-    /// let mut map = enum_map! { E::A => 1, E::B => 2, E::C => 3};
-    /// map.as_mut_array()[1] = 42;
-    /// assert_eq!(map.as_array(), &[1, 42, 3]);
+    /// struct PlayerId(Role);
+    ///
+    /// impl From<PlayerId> for Role {
+    ///     fn from(id: PlayerId) -> Self {
+    ///         id.0
+    ///     }
+    /// }
+    ///
+    /// let scores = enum_map! { Role::Attacker => 3, Role::Defender => 5 };
+    /// assert_eq!(*scores.at(PlayerId(Role::Defender)), 5);
     /// ```
-    pub fn as_mut_array(&mut self) -> &mut K::Array {
-        &mut self.array
+    #[inline]
+    pub fn at<Q: Into<K>>(&self, key: Q) -> &V {
+        &self[key.into()]
     }
 
-    /// Converts an enum map to a slice representing values.
-    ///
-    /// The order of elements is deterministic, and when using [macro@Enum]
-    /// derive it will be the order in which enum variants are declared.
+    /// Returns a mutable reference to the value for a key, converting it
+    /// from another type first.
     ///
     /// # Examples
     ///
     /// ```
     /// use enum_map::{enum_map, Enum};
     ///
-    /// #[derive(Enum, PartialEq)]
-    /// enum E {
-    ///     A,
-    ///     B,
-    ///     C,
+    /// #[derive(Enum)]
+    /// enum Role {
+    ///     Attacker,
+    ///     Defender,
     /// }
     ///
-    /// let map = enum_map! { E::A => 1, E::B => 2, E::C => 3};
-    /// assert_eq!(map.as_slice(), &[1, 2, 3]);
+    /// struct PlayerId(Role);
+    ///
+    /// impl From<PlayerId> for Role {
+    ///     fn from(id: PlayerId) -> Self {
+    ///         id.0
+    ///     }
+    /// }
+    ///
+    /// let mut scores = enum_map! { Role::Attacker => 3, Role::Defender => 5 };
+    /// *scores.at_mut(PlayerId(Role::Attacker)) += 1;
+    /// assert_eq!(scores[Role::Attacker], 4);
     /// ```
     #[inline]
-    pub fn as_slice(&self) -> &[V] {
-        unsafe { slice::from_raw_parts(ptr::addr_of!(self.array).cast(), K::Array::LENGTH) }
+    pub fn at_mut<Q: Into<K>>(&mut self, key: Q) -> &mut V {
+        &mut self[key.into()]
     }
 
-    /// Converts a mutable enum map to a mutable slice representing values.
+    /// Swaps two indexes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::enum_map;
+    ///
+    /// let mut map = enum_map! { false => 0, true => 1 };
+    /// map.swap(false, true);
+    /// assert_eq!(map[false], 1);
+    /// assert_eq!(map[true], 0);
+    /// ```
     #[inline]
-    pub fn as_mut_slice(&mut self) -> &mut [V] {
-        unsafe { slice::from_raw_parts_mut(ptr::addr_of_mut!(self.array).cast(), K::Array::LENGTH) }
+    pub fn swap(&mut self, a: K, b: K) {
+        self.as_mut_slice().swap(a.into_usize(), b.into_usize());
     }
 
-    /// Returns an enum map with function `f` applied to each element in order.
+    /// Swaps two indexes, returning values that were stored under `a` and
+    /// `b` before swapping.
     ///
     /// # Examples
     ///
     /// ```
     /// use enum_map::enum_map;
     ///
-    /// let a = enum_map! { false => 0, true => 1 };
-    /// let b = a.map(|_, x| f64::from(x) + 0.5);
-    /// assert_eq!(b, enum_map! { false => 0.5, true => 1.5 });
+    /// let mut map = enum_map! { false => 0, true => 1 };
+    /// assert_eq!(map.swap_take(false, true), (0, 1));
+    /// assert_eq!(map[false], 1);
+    /// assert_eq!(map[true], 0);
     /// ```
-    pub fn map<F, T>(self, mut f: F) -> EnumMap<K, T>
+    #[inline]
+    pub fn swap_take(&mut self, a: K, b: K) -> (V, V)
     where
-        F: FnMut(K, V) -> T,
-        K: EnumArray<T>,
+        V: Copy,
     {
-        struct DropOnPanic<K, V>
-        where
-            K: EnumArray<V>,
-        {
-            position: usize,
-            map: ManuallyDrop<EnumMap<K, V>>,
-        }
-        impl<K, V> Drop for DropOnPanic<K, V>
+        let slice = self.as_mut_slice();
+        let (a, b) = (a.into_usize(), b.into_usize());
+        slice.swap(a, b);
+        (slice[b], slice[a])
+    }
+
+    /// Reverses the order of values in place.
+    ///
+    /// This operates in index order, so the value of the first key is
+    /// swapped with the value of the last key, and so on.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::{enum_map, Enum};
+    ///
+    /// #[derive(Enum)]
+    /// enum E {
+    ///     A,
+    ///     B,
+    ///     C,
+    /// }
+    ///
+    /// let mut map = enum_map! { E::A => 1, E::B => 2, E::C => 3 };
+    /// map.reverse();
+    /// assert_eq!(map.as_slice(), &[3, 2, 1]);
+    /// ```
+    #[inline]
+    pub fn reverse(&mut self) {
+        self.as_mut_slice().reverse();
+    }
+
+    /// Replaces the value stored under a key, returning the old value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::enum_map;
+    ///
+    /// let mut map = enum_map! { false => 1, true => 2 };
+    /// assert_eq!(map.replace(true, 3), 2);
+    /// assert_eq!(map[true], 3);
+    /// ```
+    #[inline]
+    pub fn replace(&mut self, key: K, value: V) -> V {
+        mem::replace(&mut self[key], value)
+    }
+
+    /// Moves every value from another enum map into this one.
+    ///
+    /// All values previously stored in this map are dropped. Unlike
+    /// [`Extend`], which only overwrites keys present in an iterator, this
+    /// always overwrites the whole map. It's equivalent to `*self = other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::enum_map;
+    ///
+    /// let mut map = enum_map! { false => 1, true => 2 };
+    /// map.overwrite_from(enum_map! { false => 3, true => 4 });
+    /// assert_eq!(map.as_slice(), &[3, 4]);
+    /// ```
+    #[inline]
+    pub fn overwrite_from(&mut self, other: Self) {
+        *self = other;
+    }
+
+    /// Fills the enum map with clones of a value.
+    ///
+    /// The value itself is moved into the last element, so it's cloned one
+    /// time less than the number of variants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::{enum_map, Enum};
+    ///
+    /// #[derive(Enum)]
+    /// enum E {
+    ///     A,
+    ///     B,
+    /// }
+    ///
+    /// let mut map = enum_map! { E::A => 1, E::B => 2 };
+    /// map.fill(5);
+    /// assert_eq!(map.as_slice(), &[5, 5]);
+    /// ```
+    pub fn fill(&mut self, value: V)
+    where
+        V: Clone,
+    {
+        if let Some((last, rest)) = self.as_mut_slice().split_last_mut() {
+            for v in rest {
+                v.clone_from(&value);
+            }
+            *last = value;
+        }
+    }
+
+    /// Replaces every value with the result of calling `f` with its key.
+    ///
+    /// Unlike [`clear`](Self::clear), this doesn't require `V` to implement
+    /// `Default`. Keys are visited in order, and an old value is dropped
+    /// after its replacement is computed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::enum_map;
+    ///
+    /// let mut map = enum_map! { false => vec![1, 2], true => vec![3] };
+    /// map.clear_with(|key| vec![u8::from(key)]);
+    /// assert_eq!(map, enum_map! { false => vec![0], true => vec![1] });
+    /// ```
+    pub fn clear_with<F>(&mut self, mut f: F)
+    where
+        F: FnMut(K) -> V,
+    {
+        for (key, value) in self {
+            *value = f(key);
+        }
+    }
+
+    /// Consumes an enum map and returns the underlying array.
+    ///
+    /// The order of elements is deterministic, and when using [macro@Enum]
+    /// derive it will be the order in which enum variants are declared.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::{enum_map, Enum};
+    ///
+    /// #[derive(Enum, PartialEq)]
+    /// enum E {
+    ///     A,
+    ///     B,
+    ///     C,
+    /// }
+    ///
+    /// let map = enum_map! { E::A => 1, E::B => 2, E::C => 3};
+    /// assert_eq!(map.into_array(), [1, 2, 3]);
+    /// ```
+    pub fn into_array(self) -> K::Array {
+        self.array
+    }
+
+    /// Returns a reference to the underlying array.
+    ///
+    /// The order of elements is deterministic, and when using [macro@Enum]
+    /// derive it will be the order in which enum variants are declared.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::{enum_map, Enum};
+    ///
+    /// #[derive(Enum, PartialEq)]
+    /// enum E {
+    ///     A,
+    ///     B,
+    ///     C,
+    /// }
+    ///
+    /// let map = enum_map! { E::A => 1, E::B => 2, E::C => 3};
+    /// assert_eq!(map.as_array(), &[1, 2, 3]);
+    /// ```
+    pub const fn as_array(&self) -> &K::Array {
+        &self.array
+    }
+
+    /// Returns a mutable reference to the underlying array.
+    ///
+    /// The order of elements is deterministic, and when using [macro@Enum]
+    /// derive it will be the order in which enum variants are declared.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::{enum_map, Enum};
+    ///
+    /// #[derive(Enum, PartialEq)]
+    /// enum E {
+    ///     A,
+    ///     B,
+    ///     C,
+    /// }
+    ///
+    /// let mut map = enum_map! { E::A => 1, E::B => 2, E::C => 3};
+    /// map.as_mut_array()[1] = 42;
+    /// assert_eq!(map.as_array(), &[1, 42, 3]);
+    /// ```
+    pub fn as_mut_array(&mut self) -> &mut K::Array {
+        &mut self.array
+    }
+
+    /// Converts an enum map to a slice representing values.
+    ///
+    /// The order of elements is deterministic, and when using [macro@Enum]
+    /// derive it will be the order in which enum variants are declared.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::{enum_map, Enum};
+    ///
+    /// #[derive(Enum, PartialEq)]
+    /// enum E {
+    ///     A,
+    ///     B,
+    ///     C,
+    /// }
+    ///
+    /// let map = enum_map! { E::A => 1, E::B => 2, E::C => 3};
+    /// assert_eq!(map.as_slice(), &[1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn as_slice(&self) -> &[V] {
+        unsafe { slice::from_raw_parts(ptr::addr_of!(self.array).cast(), K::Array::LENGTH) }
+    }
+
+    /// Converts a mutable enum map to a mutable slice representing values.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [V] {
+        unsafe { slice::from_raw_parts_mut(ptr::addr_of_mut!(self.array).cast(), K::Array::LENGTH) }
+    }
+
+    /// Returns an iterator over maximal runs of adjacent values for which
+    /// `same` holds between each pair of neighbours.
+    ///
+    /// Each item consists of the first key of a run, the last key of a run
+    /// and the values in that run. The runs are visited in the order of
+    /// keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::{enum_map, Enum};
+    ///
+    /// #[derive(Debug, Enum, PartialEq)]
+    /// enum E {
+    ///     A,
+    ///     B,
+    ///     C,
+    ///     D,
+    /// }
+    ///
+    /// let map = enum_map! { E::A => 1, E::B => 1, E::C => 2, E::D => 1 };
+    /// let mut runs = map.chunk_by_value(|a, b| a == b);
+    /// assert_eq!(runs.next(), Some((E::A, E::B, &[1, 1][..])));
+    /// assert_eq!(runs.next(), Some((E::C, E::C, &[2][..])));
+    /// assert_eq!(runs.next(), Some((E::D, E::D, &[1][..])));
+    /// assert_eq!(runs.next(), None);
+    /// ```
+    pub fn chunk_by_value<F>(&self, mut same: F) -> impl Iterator<Item = (K, K, &[V])>
+    where
+        F: FnMut(&V, &V) -> bool,
+    {
+        let mut rest = self.as_slice();
+        let mut start = 0;
+        core::iter::from_fn(move || {
+            if rest.is_empty() {
+                return None;
+            }
+            let mut len = 1;
+            while len < rest.len() && same(&rest[len - 1], &rest[len]) {
+                len += 1;
+            }
+            let (run, tail) = rest.split_at(len);
+            let first = K::from_usize(start);
+            start += len;
+            rest = tail;
+            Some((first, K::from_usize(start - 1), run))
+        })
+    }
+
+    /// Folds values into one by repeatedly applying a fallible function,
+    /// stopping at the first error.
+    ///
+    /// The first value is used as the initial accumulator, and `f` is
+    /// called with the accumulator and each remaining key and value in
+    /// order. Returns `Ok(None)` for an enum without variants.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error returned by `f`. Values that weren't visited
+    /// yet are dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::enum_map;
+    ///
+    /// let map = enum_map! { 0 => 1, 1 => 2, _ => 3 };
+    /// let sum = map.try_reduce(|acc, _, value| u8::checked_add(acc, value).ok_or("overflow"));
+    /// assert_eq!(sum, Err("overflow"));
+    /// ```
+    pub fn try_reduce<E, F>(self, mut f: F) -> Result<Option<V>, E>
+    where
+        F: FnMut(V, K, V) -> Result<V, E>,
+    {
+        let mut iter = self.into_iter();
+        let mut accumulator = match iter.next() {
+            Some((_, value)) => value,
+            None => return Ok(None),
+        };
+        for (key, value) in iter {
+            accumulator = f(accumulator, key, value)?;
+        }
+        Ok(Some(accumulator))
+    }
+
+    /// Splits values into `N`-element arrays, starting at the beginning,
+    /// and a remainder slice with length strictly less than `N`.
+    ///
+    /// This is the enum map equivalent of slice `as_chunks` method.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::{enum_map, Enum};
+    ///
+    /// #[derive(Enum)]
+    /// enum E {
+    ///     A,
+    ///     B,
+    ///     C,
+    ///     D,
+    ///     E,
+    /// }
+    ///
+    /// let map = enum_map! { E::A => 1, E::B => 2, E::C => 3, E::D => 4, E::E => 5 };
+    /// let (chunks, remainder) = map.as_chunks::<2>();
+    /// assert_eq!(chunks, &[[1, 2], [3, 4]]);
+    /// assert_eq!(remainder, &[5]);
+    /// ```
+    #[inline]
+    pub fn as_chunks<const N: usize>(&self) -> (&[[V; N]], &[V]) {
+        assert!(N != 0, "chunk size must be non-zero");
+        let len = self.len() / N;
+        let (multiple_of_n, remainder) = self.as_slice().split_at(len * N);
+        // SAFETY: `multiple_of_n` contains exactly `len * N` elements, and
+        // an array has the same layout as its elements laid out in a row.
+        let chunks = unsafe { slice::from_raw_parts(multiple_of_n.as_ptr().cast(), len) };
+        (chunks, remainder)
+    }
+
+    /// Divides values into two slices, the first one containing values for
+    /// keys before `key`, and the second one containing the value for `key`
+    /// and values for keys after it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::enum_map;
+    ///
+    /// let map = enum_map! { 0 => 1, 1 => 2, 2 => 3, _ => 0 };
+    /// let (before, after) = map.split_at(2u8);
+    /// assert_eq!(before, [1, 2]);
+    /// assert_eq!(after.len(), 254);
+    /// ```
+    #[inline]
+    pub fn split_at(&self, key: K) -> (&[V], &[V]) {
+        self.as_slice().split_at(key.into_usize())
+    }
+
+    /// Divides values into two mutable slices, the first one containing
+    /// values for keys before `key`, and the second one containing the value
+    /// for `key` and values for keys after it.
+    ///
+    /// Use [`split_iter_mut`](Self::split_iter_mut) to keep keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::enum_map;
+    ///
+    /// let mut map = enum_map! { false => 1, true => 2 };
+    /// let (before, after) = map.split_at_mut(true);
+    /// before[0] += 10;
+    /// after[0] += 20;
+    /// assert_eq!(map.as_slice(), [11, 22]);
+    /// ```
+    #[inline]
+    pub fn split_at_mut(&mut self, key: K) -> (&mut [V], &mut [V]) {
+        self.as_mut_slice().split_at_mut(key.into_usize())
+    }
+
+    /// Moves values into an enum map with different keys, computing the new
+    /// key of each value with `f`.
+    ///
+    /// Keys of the new map not returned by `f` are filled with default
+    /// values. When `f` returns the same key more than once, the value
+    /// visited later overwrites the earlier one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::{enum_map, Enum};
+    ///
+    /// #[derive(Debug, Enum, PartialEq)]
+    /// enum Old {
+    ///     A,
+    ///     B,
+    /// }
+    ///
+    /// #[derive(Debug, Enum, PartialEq)]
+    /// enum New {
+    ///     X,
+    ///     Y,
+    ///     Z,
+    /// }
+    ///
+    /// let map = enum_map! { Old::A => 1, Old::B => 2 };
+    /// let remapped = map.remap(|key| match key {
+    ///     Old::A => New::Z,
+    ///     Old::B => New::X,
+    /// });
+    /// assert_eq!(remapped, enum_map! { New::X => 2, New::Y => 0, New::Z => 1 });
+    /// ```
+    pub fn remap<L, F>(self, mut f: F) -> EnumMap<L, V>
+    where
+        L: EnumArray<V>,
+        V: Default,
+        F: FnMut(K) -> L,
+    {
+        let mut result = EnumMap::default();
+        for (key, value) in self {
+            result[f(key)] = value;
+        }
+        result
+    }
+
+    /// Moves values into an enum map with different keys, where `f` returns
+    /// every new key that should get the value of a key.
+    ///
+    /// A value is cloned for each new key, except the last one, which gets
+    /// the value itself. Keys of the new map not returned by `f` are filled
+    /// with default values. When a new key is returned more than once, the
+    /// value visited later overwrites the earlier one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::{enum_map, Enum};
+    ///
+    /// #[derive(Debug, Enum, PartialEq)]
+    /// enum Side {
+    ///     Left,
+    ///     Right,
+    /// }
+    ///
+    /// #[derive(Debug, Enum, PartialEq)]
+    /// enum Corner {
+    ///     TopLeft,
+    ///     TopRight,
+    ///     BottomLeft,
+    ///     BottomRight,
+    /// }
+    ///
+    /// let sides = enum_map! { Side::Left => 1, Side::Right => 2 };
+    /// let corners = sides.expand(|side| match side {
+    ///     Side::Left => [Corner::TopLeft, Corner::BottomLeft],
+    ///     Side::Right => [Corner::TopRight, Corner::BottomRight],
+    /// });
+    /// assert_eq!(corners.as_slice(), [1, 2, 1, 2]);
+    /// ```
+    pub fn expand<L, F, I>(self, mut f: F) -> EnumMap<L, V>
+    where
+        L: EnumArray<V>,
+        V: Clone + Default,
+        F: FnMut(K) -> I,
+        I: IntoIterator<Item = L>,
+    {
+        let mut result = EnumMap::default();
+        for (key, value) in self {
+            let mut new_keys = f(key).into_iter().peekable();
+            while let Some(new_key) = new_keys.next() {
+                if new_keys.peek().is_none() {
+                    result[new_key] = value;
+                    break;
+                }
+                result[new_key] = value.clone();
+            }
+        }
+        result
+    }
+
+    /// Creates an enum map from a map keyed by an older version of an enum,
+    /// computing the new key of each value with `map_key`.
+    ///
+    /// This is meant for schema evolution, like when a variant is added to
+    /// an enum and maps stored with the old version need to be carried
+    /// forward. It works like [`EnumMap::remap`]: variants of the new enum
+    /// not returned by `map_key` are filled with default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::{enum_map, Enum, EnumMap};
+    ///
+    /// #[derive(Enum)]
+    /// enum V1 {
+    ///     A,
+    ///     B,
+    ///     C,
+    /// }
+    ///
+    /// #[derive(Debug, Enum, PartialEq)]
+    /// enum V2 {
+    ///     A,
+    ///     B,
+    ///     C,
+    ///     D,
+    /// }
+    ///
+    /// let old = enum_map! { V1::A => 1, V1::B => 2, V1::C => 3 };
+    /// let new = EnumMap::upgrade_from(old, |key| match key {
+    ///     V1::A => V2::A,
+    ///     V1::B => V2::B,
+    ///     V1::C => V2::C,
+    /// });
+    /// assert_eq!(new, enum_map! { V2::A => 1, V2::B => 2, V2::C => 3, V2::D => 0 });
+    /// ```
+    pub fn upgrade_from<Old, F>(old: EnumMap<Old, V>, map_key: F) -> Self
+    where
+        Old: EnumArray<V>,
+        V: Default,
+        F: FnMut(Old) -> K,
+    {
+        old.remap(map_key)
+    }
+
+    /// Moves values into an enum map with different keys, computing the new
+    /// key of each value with a fallible function.
+    ///
+    /// Keys of the new map not returned by `f` are filled with default
+    /// values. When `f` returns the same key more than once, the value
+    /// visited later overwrites the earlier one.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error returned by `f`. All values are dropped in
+    /// that case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::{enum_map, Enum, EnumMap};
+    ///
+    /// #[derive(Debug, Enum, PartialEq)]
+    /// enum Old {
+    ///     A,
+    ///     B,
+    /// }
+    ///
+    /// #[derive(Debug, Enum, PartialEq)]
+    /// enum New {
+    ///     X,
+    ///     Y,
+    ///     Z,
+    /// }
+    ///
+    /// let map = enum_map! { Old::A => 1, Old::B => 2 };
+    /// let remapped = map.try_map_keys(|key| match key {
+    ///     Old::A => Ok(New::Z),
+    ///     Old::B => Ok(New::X),
+    /// });
+    /// assert_eq!(remapped, Ok::<_, ()>(enum_map! { New::X => 2, New::Y => 0, New::Z => 1 }));
+    /// ```
+    pub fn try_map_keys<J, E, F>(self, mut f: F) -> Result<EnumMap<J, V>, E>
+    where
+        J: EnumArray<V>,
+        V: Default,
+        F: FnMut(K) -> Result<J, E>,
+    {
+        let mut result = EnumMap::default();
+        for (key, value) in self {
+            result[f(key)?] = value;
+        }
+        Ok(result)
+    }
+
+    /// Combines two enum maps into one with twice as many keys by
+    /// interleaving their values.
+    ///
+    /// The resulting map contains values in the order `self[0]`,
+    /// `other[0]`, `self[1]`, `other[1]` and so on.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `J` doesn't have exactly twice as many variants as `K`.
+    /// Both maps are dropped in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::{enum_map, Enum, EnumMap};
+    ///
+    /// #[derive(Enum)]
+    /// enum Channel {
+    ///     A,
+    ///     B,
+    /// }
+    ///
+    /// #[derive(Enum)]
+    /// enum Sample {
+    ///     A0,
+    ///     B0,
+    ///     A1,
+    ///     B1,
+    /// }
+    ///
+    /// let left = enum_map! { Channel::A => 1, Channel::B => 2 };
+    /// let right = enum_map! { Channel::A => 3, Channel::B => 4 };
+    /// let samples: EnumMap<Sample, _> = left.interleave(right);
+    /// assert_eq!(samples.as_slice(), &[1, 3, 2, 4]);
+    /// ```
+    pub fn interleave<J: EnumArray<V>>(self, other: Self) -> EnumMap<J, V> {
+        assert!(
+            K::Array::LENGTH.checked_mul(2) == Some(J::Array::LENGTH),
+            "target enum must have twice as many variants",
+        );
+        let mut first = self.into_values();
+        let mut second = other.into_values();
+        let mut take_first = true;
+        enum_map! {
+            _ => {
+                let values = if take_first { &mut first } else { &mut second };
+                take_first = !take_first;
+                values.next().unwrap()
+            }
+        }
+    }
+
+    /// Compares values of two enum maps after sorting them, ignoring which
+    /// keys they are stored under.
+    ///
+    /// Unlike the `Ord` implementation, which compares values
+    /// lexicographically in key order, this compares the smallest values of
+    /// both maps first, then the second smallest, and so on. Two maps
+    /// compare as equal when they contain the same values, regardless of
+    /// their keys. References to values are sorted in enum maps, so this
+    /// doesn't allocate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::enum_map;
+    /// use std::cmp::Ordering;
+    ///
+    /// let a = enum_map! { false => 1, true => 2 };
+    /// let b = enum_map! { false => 2, true => 1 };
+    /// assert_eq!(a.cmp(&b), Ordering::Less);
+    /// assert_eq!(a.cmp_by_value(&b), Ordering::Equal);
+    /// ```
+    pub fn cmp_by_value<'a>(&'a self, other: &'a Self) -> Ordering
+    where
+        K: EnumArray<&'a V>,
+        V: Ord,
+    {
+        let mut this: EnumMap<K, &V> = enum_map! { key => &self[key] };
+        let mut other: EnumMap<K, &V> = enum_map! { key => &other[key] };
+        this.as_mut_slice().sort_unstable();
+        other.as_mut_slice().sort_unstable();
+        this.as_slice().cmp(other.as_slice())
+    }
+
+    /// Returns the key of the greatest value.
+    ///
+    /// If several values are equally maximum, the key with the lowest index
+    /// is returned. Returns `None` only when `K` has no variants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::{enum_map, Enum};
+    ///
+    /// #[derive(Debug, Enum, PartialEq)]
+    /// enum Action {
+    ///     Wait,
+    ///     Attack,
+    ///     Flee,
+    /// }
+    ///
+    /// let scores = enum_map! { Action::Wait => 1, Action::Attack => 5, Action::Flee => 5 };
+    /// assert_eq!(scores.max_key(), Some(Action::Attack));
+    /// ```
+    pub fn max_key(&self) -> Option<K>
+    where
+        V: Ord,
+    {
+        self.max_key_by(Ord::cmp)
+    }
+
+    /// Returns the key of the greatest value with respect to the specified
+    /// comparison function.
+    ///
+    /// If several values are equally maximum, the key with the lowest index
+    /// is returned. Returns `None` only when `K` has no variants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::enum_map;
+    ///
+    /// let map = enum_map! { false => 2.5, true => -1.0 };
+    /// assert_eq!(map.max_key_by(|a: &f64, b| a.partial_cmp(b).unwrap()), Some(false));
+    /// ```
+    pub fn max_key_by<F>(&self, mut compare: F) -> Option<K>
+    where
+        F: FnMut(&V, &V) -> Ordering,
+    {
+        self.extreme_key_by(|a, b| compare(a, b) == Ordering::Greater)
+    }
+
+    /// Returns the key of the value that gives the greatest value from
+    /// the specified function.
+    ///
+    /// If several values are equally maximum, the key with the lowest index
+    /// is returned. Returns `None` only when `K` has no variants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::enum_map;
+    ///
+    /// let map = enum_map! { false => -3, true => 2 };
+    /// assert_eq!(map.max_key_by_key(|x: &i32| x.abs()), Some(false));
+    /// ```
+    pub fn max_key_by_key<B, F>(&self, mut f: F) -> Option<K>
+    where
+        B: Ord,
+        F: FnMut(&V) -> B,
+    {
+        self.max_key_by(|a, b| f(a).cmp(&f(b)))
+    }
+
+    /// Returns the key of the least value.
+    ///
+    /// If several values are equally minimum, the key with the lowest index
+    /// is returned. Returns `None` only when `K` has no variants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::enum_map;
+    ///
+    /// let map = enum_map! { false => 2, true => 1 };
+    /// assert_eq!(map.min_key(), Some(true));
+    /// ```
+    pub fn min_key(&self) -> Option<K>
+    where
+        V: Ord,
+    {
+        self.min_key_by(Ord::cmp)
+    }
+
+    /// Returns the key of the least value with respect to the specified
+    /// comparison function.
+    ///
+    /// If several values are equally minimum, the key with the lowest index
+    /// is returned. Returns `None` only when `K` has no variants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::enum_map;
+    ///
+    /// let map = enum_map! { false => 2.5, true => -1.0 };
+    /// assert_eq!(map.min_key_by(|a: &f64, b| a.partial_cmp(b).unwrap()), Some(true));
+    /// ```
+    pub fn min_key_by<F>(&self, mut compare: F) -> Option<K>
+    where
+        F: FnMut(&V, &V) -> Ordering,
+    {
+        self.extreme_key_by(|a, b| compare(a, b) == Ordering::Less)
+    }
+
+    /// Returns the key of the value that gives the least value from
+    /// the specified function.
+    ///
+    /// If several values are equally minimum, the key with the lowest index
+    /// is returned. Returns `None` only when `K` has no variants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::enum_map;
+    ///
+    /// let map = enum_map! { false => -3, true => 2 };
+    /// assert_eq!(map.min_key_by_key(|x: &i32| x.abs()), Some(true));
+    /// ```
+    pub fn min_key_by_key<B, F>(&self, mut f: F) -> Option<K>
+    where
+        B: Ord,
+        F: FnMut(&V) -> B,
+    {
+        self.min_key_by(|a, b| f(a).cmp(&f(b)))
+    }
+
+    /// Returns the entry with the greatest value.
+    ///
+    /// If several values are equally maximum, the entry with the lowest
+    /// index is returned. Returns `None` only when `K` has no variants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::enum_map;
+    ///
+    /// let map = enum_map! { false => 2, true => 1 };
+    /// assert_eq!(map.max_entry(), Some((false, &2)));
+    /// ```
+    pub fn max_entry(&self) -> Option<(K, &V)>
+    where
+        V: Ord,
+    {
+        self.extreme_entry_by(|a, b| a > b)
+    }
+
+    /// Returns the entry with the least value.
+    ///
+    /// If several values are equally minimum, the entry with the lowest
+    /// index is returned. Returns `None` only when `K` has no variants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::enum_map;
+    ///
+    /// let map = enum_map! { false => 2, true => 1 };
+    /// assert_eq!(map.min_entry(), Some((true, &1)));
+    /// ```
+    pub fn min_entry(&self) -> Option<(K, &V)>
+    where
+        V: Ord,
+    {
+        self.extreme_entry_by(|a, b| a < b)
+    }
+
+    fn extreme_key_by(&self, replaces: impl FnMut(&V, &V) -> bool) -> Option<K> {
+        self.extreme_index_by(replaces).map(K::from_usize)
+    }
+
+    fn extreme_entry_by(&self, replaces: impl FnMut(&V, &V) -> bool) -> Option<(K, &V)> {
+        let slice = self.as_slice();
+        self.extreme_index_by(replaces)
+            .map(|index| (K::from_usize(index), &slice[index]))
+    }
+
+    fn extreme_index_by(&self, mut replaces: impl FnMut(&V, &V) -> bool) -> Option<usize> {
+        let slice = self.as_slice();
+        (0..slice.len()).reduce(|best, index| {
+            if replaces(&slice[index], &slice[best]) {
+                index
+            } else {
+                best
+            }
+        })
+    }
+
+    /// Returns the first key whose value satisfies a predicate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::{enum_map, Enum};
+    ///
+    /// #[derive(Debug, Enum, PartialEq)]
+    /// enum Example {
+    ///     A,
+    ///     B,
+    ///     C,
+    /// }
+    ///
+    /// let map = enum_map! { Example::A => 1, Example::B => 4, Example::C => 6 };
+    /// assert_eq!(map.find_key(|&x| x % 2 == 0), Some(Example::B));
+    /// assert_eq!(map.find_key(|&x| x > 10), None);
+    /// ```
+    pub fn find_key<P>(&self, predicate: P) -> Option<K>
+    where
+        P: FnMut(&V) -> bool,
+    {
+        self.as_slice()
+            .iter()
+            .position(predicate)
+            .map(K::from_usize)
+    }
+
+    /// Returns the first entry whose value satisfies a predicate, with
+    /// a mutable reference to the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::enum_map;
+    ///
+    /// let mut map = enum_map! { false => 1, true => 4 };
+    /// if let Some((key, value)) = map.find_value_mut(|&x| x > 2) {
+    ///     assert!(key);
+    ///     *value = 0;
+    /// }
+    /// assert_eq!(map[true], 0);
+    /// ```
+    pub fn find_value_mut<P>(&mut self, mut predicate: P) -> Option<(K, &mut V)>
+    where
+        P: FnMut(&V) -> bool,
+    {
+        self.as_mut_slice()
+            .iter_mut()
+            .enumerate()
+            .find(|(_, value)| predicate(value))
+            .map(|(index, value)| (K::from_usize(index), value))
+    }
+
+    /// Clones an enum map with a fallible clone function.
+    ///
+    /// This is useful for values that cannot implement [`Clone`], like
+    /// values that need a `try_clone` method to be duplicated.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error returned by `clone_fn`. Values cloned up
+    /// to that point are dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::enum_map;
+    ///
+    /// let map = enum_map! { false => 1, true => 2 };
+    /// let clone = map.try_clone(|&x| if x > 0 { Ok(x) } else { Err(()) });
+    /// assert_eq!(clone, Ok(map));
+    /// let clone = map.try_clone(|&x| if x > 1 { Ok(x) } else { Err(()) });
+    /// assert_eq!(clone, Err(()));
+    /// ```
+    pub fn try_clone<E, F>(&self, mut clone_fn: F) -> Result<Self, E>
+    where
+        F: FnMut(&V) -> Result<V, E>,
+    {
+        Ok(enum_map! { key => clone_fn(&self[key])? })
+    }
+
+    /// Returns the first key, its value, and the rest of values, or `None`
+    /// if `K` has no variants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::{enum_map, Enum};
+    ///
+    /// #[derive(Debug, Enum, PartialEq)]
+    /// enum E {
+    ///     A,
+    ///     B,
+    ///     C,
+    /// }
+    ///
+    /// let map = enum_map! { E::A => 1, E::B => 2, E::C => 3 };
+    /// assert_eq!(map.split_first(), Some((E::A, &1, &[2, 3][..])));
+    /// ```
+    pub fn split_first(&self) -> Option<(K, &V, &[V])> {
+        let (first, rest) = self.as_slice().split_first()?;
+        Some((K::from_usize(0), first, rest))
+    }
+
+    /// Returns the last key, its value, and the rest of values, or `None`
+    /// if `K` has no variants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::{enum_map, Enum};
+    ///
+    /// #[derive(Debug, Enum, PartialEq)]
+    /// enum E {
+    ///     A,
+    ///     B,
+    ///     C,
+    /// }
+    ///
+    /// let map = enum_map! { E::A => 1, E::B => 2, E::C => 3 };
+    /// assert_eq!(map.split_last(), Some((E::C, &3, &[1, 2][..])));
+    /// ```
+    pub fn split_last(&self) -> Option<(K, &V, &[V])> {
+        let (last, rest) = self.as_slice().split_last()?;
+        Some((K::from_usize(rest.len()), last, rest))
+    }
+
+    /// Returns the first key under which values of two enum maps differ,
+    /// or `None` if the maps are equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::{enum_map, Enum};
+    ///
+    /// #[derive(Debug, Enum, PartialEq)]
+    /// enum E {
+    ///     A,
+    ///     B,
+    ///     C,
+    /// }
+    ///
+    /// let a = enum_map! { E::A => 1, E::B => 2, E::C => 3 };
+    /// let b = enum_map! { E::A => 1, E::B => 5, E::C => 6 };
+    /// assert_eq!(a.first_difference(&b), Some(E::B));
+    /// assert_eq!(a.first_difference(&a), None);
+    /// ```
+    pub fn first_difference(&self, other: &Self) -> Option<K>
+    where
+        V: PartialEq,
+    {
+        self.as_slice()
+            .iter()
+            .zip(other.as_slice())
+            .position(|(a, b)| a != b)
+            .map(K::from_usize)
+    }
+
+    /// Replaces values matching a predicate with values computed from
+    /// their keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::enum_map;
+    ///
+    /// let mut map = enum_map! { false => 1, true => 2 };
+    /// map.reset_where_with(|_, &value| value > 1, |key| if key { 10 } else { 20 });
+    /// assert_eq!(map.as_slice(), &[1, 10]);
+    /// ```
+    pub fn reset_where_with<P, F>(&mut self, mut pred: P, mut make: F)
+    where
+        P: FnMut(K, &V) -> bool,
+        F: FnMut(K) -> V,
+    {
+        for (index, value) in self.as_mut_slice().iter_mut().enumerate() {
+            if pred(K::from_usize(index), value) {
+                *value = make(K::from_usize(index));
+            }
+        }
+    }
+
+    /// Returns the number of values for which the predicate returns `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::enum_map;
+    ///
+    /// let map = enum_map! { 0 => 5, 1 => 7, _ => 0 };
+    /// assert_eq!(map.count_where(|&value| value > 0), 2);
+    /// ```
+    pub fn count_where<P: FnMut(&V) -> bool>(&self, mut p: P) -> usize {
+        self.as_slice().iter().filter(|value| p(value)).count()
+    }
+
+    /// Returns `true` if the predicate returns `true` for every value.
+    ///
+    /// Stops at the first value for which the predicate returns `false`.
+    /// Returns `true` when `K` has no variants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::enum_map;
+    ///
+    /// let map = enum_map! { false => 2, true => 4 };
+    /// assert!(map.all(|&value| value % 2 == 0));
+    /// assert!(!map.all(|&value| value > 3));
+    /// ```
+    pub fn all<P: FnMut(&V) -> bool>(&self, p: P) -> bool {
+        self.as_slice().iter().all(p)
+    }
+
+    /// Returns `true` if the predicate returns `true` for any value.
+    ///
+    /// Stops at the first value for which the predicate returns `true`.
+    /// Returns `false` when `K` has no variants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::enum_map;
+    ///
+    /// let map = enum_map! { false => 2, true => 4 };
+    /// assert!(map.any(|&value| value > 3));
+    /// assert!(!map.any(|&value| value > 4));
+    /// ```
+    pub fn any<P: FnMut(&V) -> bool>(&self, p: P) -> bool {
+        self.as_slice().iter().any(p)
+    }
+
+    /// Returns a boolean enum map marking keys for which `pred` holds.
+    ///
+    /// The result can be converted into an [`EnumSet`] with
+    /// [`EnumSet::from_map`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::{enum_map, Enum};
+    ///
+    /// #[derive(Debug, Enum)]
+    /// enum Example {
+    ///     A,
+    ///     B,
+    ///     C,
+    /// }
+    ///
+    /// let map = enum_map! { Example::A => 0, Example::B => 5, Example::C => 2 };
+    /// let mask = map.mask(|_, &value| value > 1);
+    /// assert_eq!(mask.as_slice(), [false, true, true]);
+    /// ```
+    pub fn mask<F>(&self, mut pred: F) -> EnumMap<K, bool>
+    where
+        F: FnMut(K, &V) -> bool,
+        K: EnumArray<bool>,
+    {
+        let mut index = 0;
+        enum_map! {
+            key => {
+                let value = &self.as_slice()[index];
+                index += 1;
+                pred(key, value)
+            }
+        }
+    }
+
+    /// Binary searches sorted values for a given value.
+    ///
+    /// Values must be sorted in key order, otherwise the result is
+    /// unspecified. If the value is found, returns `Ok` with its key.
+    ///
+    /// # Errors
+    ///
+    /// If the value isn't found, returns the key before which it could be
+    /// inserted while keeping the values sorted, or `None` when it's greater
+    /// than every value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::{enum_map, Enum};
+    ///
+    /// #[derive(Debug, Enum, PartialEq)]
+    /// enum Tier {
+    ///     Bronze,
+    ///     Silver,
+    ///     Gold,
+    /// }
+    ///
+    /// let thresholds = enum_map! {
+    ///     Tier::Bronze => 0,
+    ///     Tier::Silver => 100,
+    ///     Tier::Gold => 500,
+    /// };
+    /// assert_eq!(thresholds.binary_search_value(&100), Ok(Tier::Silver));
+    /// assert_eq!(thresholds.binary_search_value(&250), Err(Some(Tier::Gold)));
+    /// assert_eq!(thresholds.binary_search_value(&1000), Err(None));
+    /// ```
+    pub fn binary_search_value(&self, target: &V) -> Result<K, Option<K>>
+    where
+        V: Ord,
+    {
+        let slice = self.as_slice();
+        slice
+            .binary_search(target)
+            .map(K::from_usize)
+            .map_err(|index| (index < slice.len()).then(|| K::from_usize(index)))
+    }
+
+    /// Returns the sum of all values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::enum_map;
+    ///
+    /// let map = enum_map! { false => 3, true => 4 };
+    /// assert_eq!(map.sum(), 7);
+    /// ```
+    pub fn sum<'a>(&'a self) -> V
+    where
+        V: Sum<&'a V>,
+    {
+        self.as_slice().iter().sum()
+    }
+
+    /// Returns the product of all values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::enum_map;
+    ///
+    /// let map = enum_map! { false => 3, true => 4 };
+    /// assert_eq!(map.product(), 12);
+    /// ```
+    pub fn product<'a>(&'a self) -> V
+    where
+        V: Product<&'a V>,
+    {
+        self.as_slice().iter().product()
+    }
+
+    /// Returns an enum map with function `f` applied to each element in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::enum_map;
+    ///
+    /// let a = enum_map! { false => 0, true => 1 };
+    /// let b = a.map(|_, x| f64::from(x) + 0.5);
+    /// assert_eq!(b, enum_map! { false => 0.5, true => 1.5 });
+    /// ```
+    pub fn map<F, T>(self, mut f: F) -> EnumMap<K, T>
+    where
+        F: FnMut(K, V) -> T,
+        K: EnumArray<T>,
+    {
+        struct DropOnPanic<K, V>
+        where
+            K: EnumArray<V>,
+        {
+            position: usize,
+            map: ManuallyDrop<EnumMap<K, V>>,
+        }
+        impl<K, V> Drop for DropOnPanic<K, V>
         where
             K: EnumArray<V>,
         {
@@ -483,4 +2069,181 @@ impl<K: EnumArray<V>, V> EnumMap<K, V> {
             }
         }
     }
+
+    /// Returns an enum map with function `f` applied to a reference to each
+    /// element in order, leaving this enum map intact.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::enum_map;
+    ///
+    /// let a = enum_map! { false => 1, true => 2 };
+    /// let b = a.map_ref(|_, x| x.to_string());
+    /// assert_eq!(b, enum_map! { false => "1".to_string(), true => "2".to_string() });
+    /// assert_eq!(a[true], 2);
+    /// ```
+    pub fn map_ref<F, T>(&self, mut f: F) -> EnumMap<K, T>
+    where
+        F: FnMut(K, &V) -> T,
+        K: EnumArray<T>,
+    {
+        let values = self.as_slice();
+        let mut position = 0;
+        enum_map! {
+            k => {
+                let value = &values[position];
+                position += 1;
+                f(k, value)
+            }
+        }
+    }
+
+    /// Returns an enum map with function `f` applied to each element in
+    /// order, threading a mutable state through the calls.
+    ///
+    /// This is useful for cumulative computations, where each value depends
+    /// on the values of previous keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::{enum_map, Enum};
+    ///
+    /// #[derive(Debug, Enum)]
+    /// enum Stage {
+    ///     Parse,
+    ///     Check,
+    ///     Emit,
+    /// }
+    ///
+    /// let durations = enum_map! { Stage::Parse => 3, Stage::Check => 5, Stage::Emit => 2 };
+    /// let finished_at = durations.scan(0, |elapsed, _, duration| {
+    ///     *elapsed += duration;
+    ///     *elapsed
+    /// });
+    /// assert_eq!(finished_at.into_array(), [3, 8, 10]);
+    /// ```
+    pub fn scan<F, S, T>(self, mut init: S, mut f: F) -> EnumMap<K, T>
+    where
+        F: FnMut(&mut S, K, V) -> T,
+        K: EnumArray<T>,
+    {
+        self.map(|key, value| f(&mut init, key, value))
+    }
+
+    /// Composes two enum maps, returning a map from keys of this map to
+    /// values of `other` stored under this map's values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::{enum_map, Enum};
+    ///
+    /// #[derive(Clone, Copy, Enum)]
+    /// enum State {
+    ///     Idle,
+    ///     Running,
+    /// }
+    ///
+    /// #[derive(Clone, Copy, Enum)]
+    /// enum Event {
+    ///     Start,
+    ///     Stop,
+    /// }
+    ///
+    /// let events = enum_map! { State::Idle => Event::Start, State::Running => Event::Stop };
+    /// let actions = enum_map! { Event::Start => "start", Event::Stop => "stop" };
+    /// let composed = events.compose(&actions);
+    /// assert_eq!(composed[State::Running], "stop");
+    /// ```
+    pub fn compose<C>(self, other: &EnumMap<V, C>) -> EnumMap<K, C>
+    where
+        K: EnumArray<C>,
+        V: EnumArray<C> + Copy,
+        C: Clone,
+    {
+        self.map(|_, value| other[value].clone())
+    }
+}
+
+impl<S, I> EnumMap<(S, I), S>
+where
+    (S, I): EnumArray<S>,
+{
+    /// Creates a state machine transition table, with `f` returning the
+    /// next state for a given state and input.
+    ///
+    /// The table is keyed by pairs of a state and an input, using the
+    /// `Enum` implementation for tuples.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::{Enum, EnumMap};
+    ///
+    /// #[derive(Clone, Copy, Debug, Enum, PartialEq)]
+    /// enum Door {
+    ///     Open,
+    ///     Closed,
+    /// }
+    ///
+    /// #[derive(Enum)]
+    /// enum Action {
+    ///     Push,
+    ///     Pull,
+    /// }
+    ///
+    /// let table = EnumMap::transition_table(|_, action| match action {
+    ///     Action::Push => Door::Open,
+    ///     Action::Pull => Door::Closed,
+    /// });
+    /// assert_eq!(table.step(Door::Closed, Action::Push), Door::Open);
+    /// ```
+    pub fn transition_table<F>(mut f: F) -> Self
+    where
+        F: FnMut(S, I) -> S,
+    {
+        enum_map! { (state, input) => f(state, input) }
+    }
+
+    /// Returns the state following `state` after receiving `input`.
+    #[inline]
+    pub fn step(&self, state: S, input: I) -> S
+    where
+        S: Copy,
+    {
+        self[(state, input)]
+    }
+
+    /// Feeds every input into a state machine starting at `start`,
+    /// returning the final state.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::{Enum, EnumMap};
+    ///
+    /// #[derive(Clone, Copy, Debug, Enum, PartialEq)]
+    /// enum Parity {
+    ///     Even,
+    ///     Odd,
+    /// }
+    ///
+    /// let table = EnumMap::transition_table(|state, flip| match (state, flip) {
+    ///     (Parity::Even, true) => Parity::Odd,
+    ///     (Parity::Odd, true) => Parity::Even,
+    ///     (state, false) => state,
+    /// });
+    /// assert_eq!(table.run(Parity::Even, [true, false, true, true]), Parity::Odd);
+    /// ```
+    pub fn run<It>(&self, start: S, inputs: It) -> S
+    where
+        S: Copy,
+        It: IntoIterator<Item = I>,
+    {
+        inputs
+            .into_iter()
+            .fold(start, |state, input| self.step(state, input))
+    }
 }
@@ -0,0 +1,55 @@
+// SPDX-FileCopyrightText: 2023 Konrad Borowski <konrad@borowski.pw>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{enum_map, EnumArray, EnumMap};
+use alloc::vec::Vec;
+use core::fmt::Debug;
+use proptest::arbitrary::{any_with, Arbitrary};
+use proptest::collection::{vec, VecStrategy};
+use proptest::strategy::{Map, Strategy};
+
+/// Requires crate feature `"proptest"`
+impl<K: EnumArray<V> + Debug, V: Arbitrary> Arbitrary for EnumMap<K, V> {
+    type Parameters = V::Parameters;
+    type Strategy = Map<VecStrategy<V::Strategy>, fn(Vec<V>) -> Self>;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        vec(any_with::<V>(args), K::LENGTH).prop_map(from_vec as fn(Vec<V>) -> Self)
+    }
+}
+
+/// Returns a strategy generating enum maps with values generated by
+/// `value_strategy`.
+///
+/// Requires crate feature `"proptest"`
+///
+/// # Examples
+///
+/// ```
+/// use enum_map::{enum_map_strategy, Enum, EnumMap};
+/// use proptest::proptest;
+///
+/// #[derive(Debug, Enum)]
+/// enum Example {
+///     A,
+///     B,
+///     C,
+/// }
+///
+/// proptest!(|(map in enum_map_strategy::<Example, _>(0..10))| {
+///     assert!(map.values().all(|&value| value < 10));
+/// });
+/// ```
+pub fn enum_map_strategy<K, S>(value_strategy: S) -> impl Strategy<Value = EnumMap<K, S::Value>>
+where
+    K: EnumArray<S::Value> + Debug,
+    S: Strategy,
+{
+    vec(value_strategy, K::LENGTH).prop_map(from_vec)
+}
+
+fn from_vec<K: EnumArray<V>, V>(values: Vec<V>) -> EnumMap<K, V> {
+    let mut values = values.into_iter();
+    enum_map! { _ => values.next().unwrap() }
+}
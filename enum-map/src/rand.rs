@@ -0,0 +1,104 @@
+// SPDX-FileCopyrightText: 2023 Konrad Borowski <konrad@borowski.pw>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{EnumArray, EnumMap};
+use rand::Rng;
+
+impl<K: EnumArray<V>, V> EnumMap<K, V> {
+    /// Returns a uniformly random key, or `None` if `K` has no variants.
+    ///
+    /// Requires crate feature `"rand"`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::enum_map;
+    ///
+    /// let map = enum_map! { false => 0, true => 1 };
+    /// let key = map.sample_key(&mut rand::thread_rng());
+    /// assert!(key.is_some());
+    /// ```
+    pub fn sample_key<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<K> {
+        let len = self.len();
+        if len == 0 {
+            None
+        } else {
+            Some(K::from_usize(rng.gen_range(0..len)))
+        }
+    }
+
+    /// Returns a uniformly random entry, or `None` if `K` has no variants.
+    ///
+    /// Requires crate feature `"rand"`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::enum_map;
+    ///
+    /// let map = enum_map! { false => 0, true => 1 };
+    /// let (key, &value) = map.sample(&mut rand::thread_rng()).unwrap();
+    /// assert_eq!(value, map[key]);
+    /// ```
+    pub fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<(K, &V)> {
+        let slice = self.as_slice();
+        if slice.is_empty() {
+            None
+        } else {
+            let index = rng.gen_range(0..slice.len());
+            Some((K::from_usize(index), &slice[index]))
+        }
+    }
+
+    /// Returns a random key, with each key picked with probability
+    /// proportional to the weight of its value.
+    ///
+    /// Keys with zero, negative or NaN weight are never picked. Returns
+    /// `None` if there is no key with a positive weight. Weights should be
+    /// finite.
+    ///
+    /// Requires crate feature `"rand"`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::{enum_map, Enum};
+    ///
+    /// #[derive(Debug, Enum, PartialEq)]
+    /// enum Loot {
+    ///     Common,
+    ///     Rare,
+    ///     Unobtainable,
+    /// }
+    ///
+    /// let chances = enum_map! {
+    ///     Loot::Common => 0.9,
+    ///     Loot::Rare => 0.1,
+    ///     Loot::Unobtainable => 0.0,
+    /// };
+    /// let loot = chances.weighted_sample(&mut rand::thread_rng(), |&chance| chance);
+    /// assert_ne!(loot, Some(Loot::Unobtainable));
+    /// ```
+    pub fn weighted_sample<R, F>(&self, rng: &mut R, mut weight: F) -> Option<K>
+    where
+        R: Rng + ?Sized,
+        F: FnMut(&V) -> f64,
+    {
+        let mut total = 0.0;
+        let mut chosen = None;
+        for (index, value) in self.as_slice().iter().enumerate() {
+            let weight = weight(value);
+            if weight > 0.0 {
+                total += weight;
+                // Replacing the current choice with probability weight / total
+                // makes every key picked with probability proportional to its
+                // weight once the scan is complete.
+                if rng.gen::<f64>() * total < weight {
+                    chosen = Some(index);
+                }
+            }
+        }
+        chosen.map(K::from_usize)
+    }
+}
@@ -0,0 +1,139 @@
+// SPDX-FileCopyrightText: 2023 Konrad Borowski <konrad@borowski.pw>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{Enum, EnumArray, EnumMap};
+use alloc::vec::Vec;
+use core::mem::{ManuallyDrop, MaybeUninit};
+use core::ptr;
+use rayon::iter::{
+    Enumerate, IndexedParallelIterator, IntoParallelIterator, Map, ParallelIterator,
+};
+use rayon::{slice, vec};
+
+fn with_key<K: Enum, T>((index, value): (usize, T)) -> (K, T) {
+    (K::from_usize(index), value)
+}
+
+/// Requires crate feature `"rayon"`
+impl<'a, K, V> IntoParallelIterator for &'a EnumMap<K, V>
+where
+    K: EnumArray<V> + Send,
+    V: Sync,
+{
+    type Iter = Map<Enumerate<slice::Iter<'a, V>>, fn((usize, &'a V)) -> (K, &'a V)>;
+    type Item = (K, &'a V);
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.as_slice()
+            .into_par_iter()
+            .enumerate()
+            .map(with_key as _)
+    }
+}
+
+/// Requires crate feature `"rayon"`
+impl<'a, K, V> IntoParallelIterator for &'a mut EnumMap<K, V>
+where
+    K: EnumArray<V> + Send,
+    V: Send,
+{
+    type Iter = Map<Enumerate<slice::IterMut<'a, V>>, fn((usize, &'a mut V)) -> (K, &'a mut V)>;
+    type Item = (K, &'a mut V);
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.as_mut_slice()
+            .into_par_iter()
+            .enumerate()
+            .map(with_key as _)
+    }
+}
+
+/// Values are moved into a `Vec` before being split between threads, so
+/// this allocates.
+///
+/// Requires crate feature `"rayon"`
+impl<K, V> IntoParallelIterator for EnumMap<K, V>
+where
+    K: EnumArray<V> + Send,
+    V: Send,
+{
+    type Iter = Map<Enumerate<vec::IntoIter<V>>, fn((usize, V)) -> (K, V)>;
+    type Item = (K, V);
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.into_values()
+            .collect::<Vec<_>>()
+            .into_par_iter()
+            .enumerate()
+            .map(with_key as _)
+    }
+}
+
+impl<K: EnumArray<V>, V> EnumMap<K, V> {
+    /// Creates an enum map by calling `f` for each key in parallel.
+    ///
+    /// Requires crate feature `"rayon"`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::EnumMap;
+    ///
+    /// let map: EnumMap<u8, u32> = EnumMap::from_par_fn(|key| u32::from(key).pow(2));
+    /// assert_eq!(map[12], 144);
+    /// ```
+    pub fn from_par_fn<F>(f: F) -> Self
+    where
+        F: Fn(K) -> V + Sync,
+        K: EnumArray<MaybeUninit<V>>,
+        V: Send,
+    {
+        let mut map = EnumMap::<K, MaybeUninit<V>>::uninit();
+        map.as_mut_slice()
+            .into_par_iter()
+            .enumerate()
+            .for_each(|(index, slot)| {
+                slot.write(f(K::from_usize(index)));
+            });
+        // SAFETY: Every value was initialized above.
+        unsafe { map.assume_init() }
+    }
+
+    /// Returns an enum map with function `f` applied to each element in
+    /// parallel.
+    ///
+    /// Requires crate feature `"rayon"`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::enum_map;
+    ///
+    /// let a = enum_map! { false => 0, true => 1 };
+    /// let b = a.par_map(|_, x| f64::from(x) + 0.5);
+    /// assert_eq!(b, enum_map! { false => 0.5, true => 1.5 });
+    /// ```
+    pub fn par_map<F, T>(self, f: F) -> EnumMap<K, T>
+    where
+        F: Fn(K, V) -> T + Sync,
+        K: EnumArray<T> + EnumArray<MaybeUninit<T>>,
+        V: Send,
+        T: Send,
+    {
+        let mut values = ManuallyDrop::new(self);
+        let mut map = EnumMap::<K, MaybeUninit<T>>::uninit();
+        map.as_mut_slice()
+            .into_par_iter()
+            .zip(values.as_mut_slice())
+            .enumerate()
+            .for_each(|(index, (slot, value))| {
+                // SAFETY: Each value is read exactly once, and `values` is
+                // never dropped. When `f` panics, unread values are leaked.
+                let value = unsafe { ptr::read(value) };
+                slot.write(f(K::from_usize(index), value));
+            });
+        // SAFETY: Every value was initialized above.
+        unsafe { map.assume_init() }
+    }
+}
@@ -3,7 +3,7 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use crate::{enum_map, EnumArray, EnumMap};
+use crate::{enum_map, EnumArray, EnumMap, EnumSet, EnumSetArray};
 use core::fmt;
 use core::marker::PhantomData;
 use serde::de::{self, Deserialize, Deserializer, Error, MapAccess, SeqAccess};
@@ -15,15 +15,24 @@ impl<K: EnumArray<V> + Serialize, V: Serialize> Serialize for EnumMap<K, V> {
         if serializer.is_human_readable() {
             serializer.collect_map(self)
         } else {
-            let mut tup = serializer.serialize_tuple(self.len())?;
-            for value in self.values() {
-                tup.serialize_element(value)?;
-            }
-            tup.end()
+            serialize_values(self, serializer)
         }
     }
 }
 
+fn serialize_values<K, V, S>(map: &EnumMap<K, V>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    K: EnumArray<V>,
+    V: Serialize,
+    S: Serializer,
+{
+    let mut tup = serializer.serialize_tuple(map.len())?;
+    for value in map.values() {
+        tup.serialize_element(value)?;
+    }
+    tup.end()
+}
+
 /// Requires crate feature `"serde"`
 impl<'de, K, V> Deserialize<'de> for EnumMap<K, V>
 where
@@ -52,25 +61,46 @@ where
         write!(formatter, "a map")
     }
 
-    fn visit_map<M: MapAccess<'de>>(self, mut access: M) -> Result<Self::Value, M::Error> {
-        let mut entries = EnumMap::default();
-        while let Some((key, value)) = access.next_entry()? {
-            entries[key] = Some(value);
-        }
-        for value in entries.values() {
-            value
-                .as_ref()
-                .ok_or_else(|| M::Error::custom("key not specified"))?;
+    fn visit_map<M: MapAccess<'de>>(self, access: M) -> Result<Self::Value, M::Error> {
+        require_all(read_entries(access)?)
+    }
+}
+
+/// Unwraps entries, failing with an error reporting the index of the first
+/// missing key.
+fn require_all<K, V, E>(mut entries: EnumMap<K, Option<V>>) -> Result<EnumMap<K, V>, E>
+where
+    K: EnumArray<V> + EnumArray<Option<V>>,
+    E: Error,
+{
+    if let Some(index) = entries.values().position(Option::is_none) {
+        return Err(E::custom(format_args!("missing key at index {index}")));
+    }
+    Ok(enum_map! { key => entries[key].take().unwrap() })
+}
+
+fn read_entries<'de, K, V, M>(mut access: M) -> Result<EnumMap<K, Option<V>>, M::Error>
+where
+    K: EnumArray<Option<V>> + Deserialize<'de>,
+    V: Deserialize<'de>,
+    M: MapAccess<'de>,
+{
+    let mut entries = EnumMap::default();
+    while let Some((key, value)) = access.next_entry::<K, V>()? {
+        let entry = &mut entries[key];
+        if entry.is_some() {
+            return Err(M::Error::custom("duplicate key"));
         }
-        Ok(enum_map! { key => entries[key].take().unwrap() })
+        *entry = Some(value);
     }
+    Ok(entries)
 }
 
 struct CompactVisitor<K, V>(PhantomData<(K, V)>);
 
 impl<'de, K, V> de::Visitor<'de> for CompactVisitor<K, V>
 where
-    K: EnumArray<V> + EnumArray<Option<V>> + Deserialize<'de>,
+    K: EnumArray<V> + EnumArray<Option<V>>,
     V: Deserialize<'de>,
 {
     type Value = EnumMap<K, V>;
@@ -96,3 +126,283 @@ where
         Ok(enum_map! { key => entries[key].take().unwrap() })
     }
 }
+
+/// Serializes a set as a sequence of its keys, in order.
+///
+/// Requires crate feature `"serde"`
+impl<K: EnumSetArray + Serialize> Serialize for EnumSet<K> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self)
+    }
+}
+
+/// Deserializes a set from a sequence of keys, which cannot contain
+/// duplicates.
+///
+/// Requires crate feature `"serde"`
+impl<'de, K: EnumSetArray + Deserialize<'de>> Deserialize<'de> for EnumSet<K> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(SetVisitor(PhantomData))
+    }
+}
+
+struct SetVisitor<K>(PhantomData<K>);
+
+impl<'de, K: EnumSetArray + Deserialize<'de>> de::Visitor<'de> for SetVisitor<K> {
+    type Value = EnumSet<K>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a sequence")
+    }
+
+    fn visit_seq<M: SeqAccess<'de>>(self, mut access: M) -> Result<Self::Value, M::Error> {
+        let mut set = EnumSet::new();
+        while let Some(key) = access.next_element()? {
+            if !set.insert(key) {
+                return Err(M::Error::custom("duplicate key"));
+            }
+        }
+        Ok(set)
+    }
+}
+
+/// Wrapper serializing an enum map as a sequence of its values.
+///
+/// Unlike [`EnumMap`] serialization, which uses a map for human-readable
+/// formats, this always serializes values alone, in the order of keys,
+/// and deserializes them back by position. This avoids repeating key
+/// names in formats like JSON.
+///
+/// Since keys aren't stored, the serialized data is only valid as long as
+/// the order of enum variants doesn't change. Adding, removing or
+/// reordering variants will silently associate values with wrong keys,
+/// or make deserialization fail.
+///
+/// Requires crate feature `"serde"`
+///
+/// # Examples
+///
+/// ```
+/// use enum_map::{enum_map, AsSeq, Enum, EnumMap};
+///
+/// #[derive(Enum)]
+/// enum Example {
+///     A,
+///     B,
+/// }
+///
+/// let map = enum_map! { Example::A => 1, Example::B => 2 };
+/// assert_eq!(serde_json::to_string(&AsSeq(&map)).unwrap(), "[1,2]");
+/// let AsSeq(map): AsSeq<EnumMap<Example, i32>> = serde_json::from_str("[3,4]").unwrap();
+/// assert_eq!(map[Example::B], 4);
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct AsSeq<T>(pub T);
+
+/// Requires crate feature `"serde"`
+impl<K: EnumArray<V>, V: Serialize> Serialize for AsSeq<EnumMap<K, V>> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_values(&self.0, serializer)
+    }
+}
+
+/// Requires crate feature `"serde"`
+impl<K: EnumArray<V>, V: Serialize> Serialize for AsSeq<&EnumMap<K, V>> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_values(self.0, serializer)
+    }
+}
+
+/// Requires crate feature `"serde"`
+impl<'de, K, V> Deserialize<'de> for AsSeq<EnumMap<K, V>>
+where
+    K: EnumArray<V> + EnumArray<Option<V>>,
+    V: Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer
+            .deserialize_tuple(K::LENGTH, CompactVisitor(PhantomData))
+            .map(AsSeq)
+    }
+}
+
+/// Wrapper deserializing an enum map with default values for missing keys.
+///
+/// Regular [`EnumMap`] deserialization fails when a key is missing. This
+/// wrapper instead initializes such keys with `V::default()`, which is
+/// useful for configuration files that should keep working when new
+/// variants are added. Unknown keys are still rejected.
+///
+/// Serialization is the same as for [`EnumMap`].
+///
+/// Requires crate feature `"serde"`
+///
+/// # Examples
+///
+/// ```
+/// use enum_map::{Enum, EnumMap, WithDefaults};
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize, Enum)]
+/// enum Example {
+///     A,
+///     B,
+/// }
+///
+/// let WithDefaults(map): WithDefaults<EnumMap<Example, i32>> =
+///     serde_json::from_str(r#"{"B": 4}"#).unwrap();
+/// assert_eq!(map[Example::A], 0);
+/// assert_eq!(map[Example::B], 4);
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct WithDefaults<T>(pub T);
+
+/// Requires crate feature `"serde"`
+impl<K: EnumArray<V> + Serialize, V: Serialize> Serialize for WithDefaults<EnumMap<K, V>> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+/// Requires crate feature `"serde"`
+impl<'de, K, V> Deserialize<'de> for WithDefaults<EnumMap<K, V>>
+where
+    K: EnumArray<V> + EnumArray<Option<V>> + Deserialize<'de>,
+    V: Deserialize<'de> + Default,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_map(WithDefaultsVisitor(PhantomData))
+        } else {
+            EnumMap::deserialize(deserializer).map(WithDefaults)
+        }
+    }
+}
+
+struct WithDefaultsVisitor<K, V>(PhantomData<(K, V)>);
+
+impl<'de, K, V> de::Visitor<'de> for WithDefaultsVisitor<K, V>
+where
+    K: EnumArray<V> + EnumArray<Option<V>> + Deserialize<'de>,
+    V: Deserialize<'de> + Default,
+{
+    type Value = WithDefaults<EnumMap<K, V>>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a map")
+    }
+
+    fn visit_map<M: MapAccess<'de>>(self, access: M) -> Result<Self::Value, M::Error> {
+        let mut entries = read_entries(access)?;
+        Ok(WithDefaults(
+            enum_map! { key => entries[key].take().unwrap_or_default() },
+        ))
+    }
+}
+
+/// Serialization of enum maps with keys represented as integers.
+///
+/// Keys are serialized as their index returned by `Enum::into_usize`
+/// instead of using their own `Serialize` implementation, which produces
+/// more compact output and doesn't require keys to implement `Serialize`
+/// or `Deserialize`. Like with [`AsSeq`], the serialized data is only
+/// valid as long as the order of enum variants doesn't change.
+///
+/// This module is meant to be used with `#[serde(with = "enum_map::int_keys")]`.
+///
+/// Requires crate feature `"serde"`
+///
+/// # Examples
+///
+/// ```
+/// use enum_map::{enum_map, Enum, EnumMap};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Enum)]
+/// enum Example {
+///     A,
+///     B,
+/// }
+///
+/// #[derive(Deserialize, Serialize)]
+/// struct Config {
+///     #[serde(with = "enum_map::int_keys")]
+///     limits: EnumMap<Example, u32>,
+/// }
+///
+/// let config = Config {
+///     limits: enum_map! { Example::A => 1, Example::B => 2 },
+/// };
+/// let json = serde_json::to_string(&config).unwrap();
+/// assert_eq!(json, r#"{"limits":{"0":1,"1":2}}"#);
+/// let config: Config = serde_json::from_str(&json).unwrap();
+/// assert_eq!(config.limits[Example::B], 2);
+/// ```
+pub mod int_keys {
+    use super::require_all;
+    use crate::{EnumArray, EnumMap};
+    use core::fmt;
+    use core::marker::PhantomData;
+    use serde::de::{self, Deserialize, Deserializer, Error, MapAccess, Unexpected};
+    use serde::ser::{Serialize, Serializer};
+
+    /// Serializes an enum map as a map with integer keys.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the serializer fails.
+    pub fn serialize<K, V, S>(map: &EnumMap<K, V>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        K: EnumArray<V>,
+        V: Serialize,
+        S: Serializer,
+    {
+        serializer.collect_map(map.as_slice().iter().enumerate())
+    }
+
+    /// Deserializes an enum map from a map with integer keys.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a key is out of range, duplicated or missing,
+    /// or if the deserializer fails.
+    pub fn deserialize<'de, K, V, D>(deserializer: D) -> Result<EnumMap<K, V>, D::Error>
+    where
+        K: EnumArray<V> + EnumArray<Option<V>>,
+        V: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(IntKeysVisitor(PhantomData))
+    }
+
+    struct IntKeysVisitor<K, V>(PhantomData<(K, V)>);
+
+    impl<'de, K, V> de::Visitor<'de> for IntKeysVisitor<K, V>
+    where
+        K: EnumArray<V> + EnumArray<Option<V>>,
+        V: Deserialize<'de>,
+    {
+        type Value = EnumMap<K, V>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(formatter, "a map with integer keys")
+        }
+
+        fn visit_map<M: MapAccess<'de>>(self, mut access: M) -> Result<Self::Value, M::Error> {
+            let mut entries = EnumMap::<K, Option<V>>::default();
+            while let Some(index) = access.next_key::<usize>()? {
+                let entry = entries.as_mut_slice().get_mut(index).ok_or_else(|| {
+                    M::Error::invalid_value(
+                        Unexpected::Unsigned(index as u64),
+                        &"an index of an enum variant",
+                    )
+                })?;
+                if entry.is_some() {
+                    return Err(M::Error::custom("duplicate key"));
+                }
+                *entry = Some(access.next_value()?);
+            }
+            require_all(entries)
+        }
+    }
+}
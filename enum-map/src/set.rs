@@ -0,0 +1,359 @@
+// SPDX-FileCopyrightText: 2023 Konrad Borowski <konrad@borowski.pw>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#![allow(clippy::module_name_repetitions)]
+
+use crate::{Enum, EnumArray, EnumMap, EnumSetArray};
+use core::fmt::{self, Debug, Formatter};
+use core::hash::{Hash, Hasher};
+use core::iter::{Extend, FromIterator, FusedIterator};
+use core::marker::PhantomData;
+use core::ops::Range;
+
+const WORD_BITS: usize = usize::BITS as usize;
+
+/// Index of a word storing bits of `EnumSet<K>`.
+///
+/// This allows storing words in an `EnumMap` sized by
+/// [`EnumSetArray::Words`].
+struct Word<K>(usize, PhantomData<fn() -> K>);
+
+impl<K: EnumSetArray> Enum for Word<K> {
+    const LENGTH: usize = crate::word_count(K::LENGTH);
+
+    #[inline]
+    fn from_usize(value: usize) -> Self {
+        Word(value, PhantomData)
+    }
+    #[inline]
+    fn into_usize(self) -> usize {
+        self.0
+    }
+}
+
+impl<K: EnumSetArray> EnumArray<usize> for Word<K> {
+    type Array = K::Words;
+}
+
+/// A set of enum keys.
+///
+/// Keys are stored as a bitmask, using a single bit per key, packed into
+/// `usize` words.
+///
+/// # Examples
+///
+/// ```
+/// use enum_map::{Enum, EnumSet};
+///
+/// #[derive(Debug, Enum, PartialEq)]
+/// enum Example {
+///     A,
+///     B,
+///     C,
+/// }
+///
+/// let mut set = EnumSet::new();
+/// set.insert(Example::A);
+/// set.insert(Example::C);
+/// assert!(set.contains(Example::C));
+/// assert!(!set.contains(Example::B));
+/// assert_eq!(set.iter().collect::<Vec<_>>(), [Example::A, Example::C]);
+/// ```
+pub struct EnumSet<K: EnumSetArray> {
+    words: EnumMap<Word<K>, usize>,
+}
+
+impl<K: EnumSetArray> EnumSet<K> {
+    /// Creates an empty set.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        EnumSet {
+            words: EnumMap::default(),
+        }
+    }
+
+    /// Creates a set by combining words of both sets.
+    #[inline]
+    fn zip_with(&self, other: &Self, f: impl Fn(usize, usize) -> usize) -> Self {
+        let mut set = Self::new();
+        let pairs = self.words.as_slice().iter().zip(other.words.as_slice());
+        for (word, (&a, &b)) in set.words.as_mut_slice().iter_mut().zip(pairs) {
+            *word = f(a, b);
+        }
+        set
+    }
+
+    /// Returns whether a key with a given index is in the set.
+    #[inline]
+    fn contains_index(&self, index: usize) -> bool {
+        self.words.as_slice()[index / WORD_BITS] & (1 << (index % WORD_BITS)) != 0
+    }
+
+    /// Returns a word storing a given key, and a mask of its bit.
+    #[inline]
+    fn word_mut(&mut self, key: K) -> (&mut usize, usize) {
+        let index = key.into_usize();
+        let word = &mut self.words.as_mut_slice()[index / WORD_BITS];
+        (word, 1 << (index % WORD_BITS))
+    }
+
+    /// Adds a key to the set, returning whether it wasn't present before.
+    #[inline]
+    pub fn insert(&mut self, key: K) -> bool {
+        let (word, mask) = self.word_mut(key);
+        let inserted = *word & mask == 0;
+        *word |= mask;
+        inserted
+    }
+
+    /// Removes a key from the set, returning whether it was present.
+    #[inline]
+    pub fn remove(&mut self, key: K) -> bool {
+        let (word, mask) = self.word_mut(key);
+        let removed = *word & mask != 0;
+        *word &= !mask;
+        removed
+    }
+
+    /// Returns whether the set contains a key.
+    #[inline]
+    pub fn contains(&self, key: K) -> bool {
+        self.contains_index(key.into_usize())
+    }
+
+    /// Returns a set containing keys present in either set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::{Enum, EnumSet};
+    ///
+    /// #[derive(Debug, Enum, PartialEq)]
+    /// enum Example {
+    ///     A,
+    ///     B,
+    ///     C,
+    /// }
+    ///
+    /// let a: EnumSet<_> = [Example::A, Example::B].into_iter().collect();
+    /// let b: EnumSet<_> = [Example::B, Example::C].into_iter().collect();
+    /// assert_eq!(a.union(&b).iter().collect::<Vec<_>>(), [Example::A, Example::B, Example::C]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        self.zip_with(other, |a, b| a | b)
+    }
+
+    /// Returns a set containing keys present in both sets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::{Enum, EnumSet};
+    ///
+    /// #[derive(Debug, Enum, PartialEq)]
+    /// enum Example {
+    ///     A,
+    ///     B,
+    ///     C,
+    /// }
+    ///
+    /// let a: EnumSet<_> = [Example::A, Example::B].into_iter().collect();
+    /// let b: EnumSet<_> = [Example::B, Example::C].into_iter().collect();
+    /// assert_eq!(a.intersection(&b).iter().collect::<Vec<_>>(), [Example::B]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.zip_with(other, |a, b| a & b)
+    }
+
+    /// Returns the number of keys in the set.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.words
+            .values()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Returns whether the set is empty.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.words.values().all(|&word| word == 0)
+    }
+
+    /// Returns an iterator over keys in the set, in key order.
+    #[inline]
+    #[must_use]
+    pub fn iter(&self) -> EnumSetIter<'_, K> {
+        EnumSetIter {
+            set: self,
+            indices: 0..K::LENGTH,
+            len: self.len(),
+        }
+    }
+}
+
+impl<K: EnumSetArray + EnumArray<bool>> EnumSet<K> {
+    /// Creates a set containing keys mapped to `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::{enum_map, EnumSet};
+    ///
+    /// let set = EnumSet::from_map(&enum_map! { false => false, true => true });
+    /// assert!(set.contains(true));
+    /// assert!(!set.contains(false));
+    /// ```
+    pub fn from_map(map: &EnumMap<K, bool>) -> Self {
+        let mut set = Self::new();
+        for (key, &present) in map {
+            if present {
+                set.insert(key);
+            }
+        }
+        set
+    }
+
+    /// Converts the set into an enum map, mapping present keys to `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::{enum_map, EnumSet};
+    ///
+    /// let mut set = EnumSet::new();
+    /// set.insert(true);
+    /// assert_eq!(set.to_map(), enum_map! { false => false, true => true });
+    /// ```
+    #[must_use]
+    pub fn to_map(&self) -> EnumMap<K, bool> {
+        let mut map = EnumMap::default();
+        for (index, present) in map.as_mut_slice().iter_mut().enumerate() {
+            *present = self.contains_index(index);
+        }
+        map
+    }
+}
+
+impl<K: EnumSetArray> Clone for EnumSet<K>
+where
+    K::Words: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        EnumSet {
+            words: self.words.clone(),
+        }
+    }
+}
+
+impl<K: EnumSetArray> Copy for EnumSet<K> where K::Words: Copy {}
+
+impl<K: EnumSetArray> Default for EnumSet<K> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: EnumSetArray> PartialEq for EnumSet<K> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.words == other.words
+    }
+}
+
+impl<K: EnumSetArray> Eq for EnumSet<K> {}
+
+impl<K: EnumSetArray> Hash for EnumSet<K> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.words.hash(state);
+    }
+}
+
+impl<K: EnumSetArray + Debug> Debug for EnumSet<K> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_set().entries(self).finish()
+    }
+}
+
+impl<K: EnumSetArray> Extend<K> for EnumSet<K> {
+    fn extend<I: IntoIterator<Item = K>>(&mut self, iter: I) {
+        for key in iter {
+            self.insert(key);
+        }
+    }
+}
+
+impl<K: EnumSetArray> FromIterator<K> for EnumSet<K> {
+    fn from_iter<I: IntoIterator<Item = K>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<'a, K: EnumSetArray> IntoIterator for &'a EnumSet<K> {
+    type Item = K;
+    type IntoIter = EnumSetIter<'a, K>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Enum set iterator
+///
+/// This struct is created by `iter` method or `into_iter` on a reference
+/// to `EnumSet`.
+pub struct EnumSetIter<'a, K: EnumSetArray> {
+    set: &'a EnumSet<K>,
+    indices: Range<usize>,
+    len: usize,
+}
+
+impl<K: EnumSetArray> Clone for EnumSetIter<'_, K> {
+    fn clone(&self) -> Self {
+        EnumSetIter {
+            set: self.set,
+            indices: self.indices.clone(),
+            len: self.len,
+        }
+    }
+}
+
+impl<K: EnumSetArray> Debug for EnumSetIter<'_, K> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("EnumSetIter")
+            .field("len", &self.len)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<K: EnumSetArray> Iterator for EnumSetIter<'_, K> {
+    type Item = K;
+    #[inline]
+    fn next(&mut self) -> Option<K> {
+        let set = self.set;
+        let index = self.indices.find(|&index| set.contains_index(index))?;
+        self.len -= 1;
+        Some(K::from_usize(index))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<K: EnumSetArray> ExactSizeIterator for EnumSetIter<'_, K> {}
+
+impl<K: EnumSetArray> FusedIterator for EnumSetIter<'_, K> {}
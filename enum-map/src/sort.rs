@@ -0,0 +1,98 @@
+// SPDX-FileCopyrightText: 2023 Konrad Borowski <konrad@borowski.pw>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{EnumArray, EnumMap};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+impl<K: EnumArray<V>, V> EnumMap<K, V> {
+    /// Returns keys ordered by their values, from the smallest.
+    ///
+    /// The sort is stable, so keys with equal values are in the iteration
+    /// order.
+    ///
+    /// Requires crate feature `"alloc"`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::{enum_map, Enum};
+    ///
+    /// #[derive(Debug, Enum, PartialEq)]
+    /// enum Category {
+    ///     A,
+    ///     B,
+    ///     C,
+    /// }
+    ///
+    /// let scores = enum_map! { Category::A => 3, Category::B => 1, Category::C => 3 };
+    /// assert_eq!(
+    ///     scores.keys_sorted_by_value(),
+    ///     [Category::B, Category::A, Category::C],
+    /// );
+    /// ```
+    pub fn keys_sorted_by_value(&self) -> Vec<K>
+    where
+        V: Ord,
+    {
+        self.keys_sorted_by(Ord::cmp)
+    }
+
+    /// Returns keys ordered by their values, from the largest.
+    ///
+    /// The sort is stable, so keys with equal values are in the iteration
+    /// order.
+    ///
+    /// Requires crate feature `"alloc"`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::{enum_map, Enum};
+    ///
+    /// #[derive(Debug, Enum, PartialEq)]
+    /// enum Category {
+    ///     A,
+    ///     B,
+    ///     C,
+    /// }
+    ///
+    /// let scores = enum_map! { Category::A => 3, Category::B => 1, Category::C => 3 };
+    /// assert_eq!(
+    ///     scores.keys_sorted_by_value_desc(),
+    ///     [Category::A, Category::C, Category::B],
+    /// );
+    /// ```
+    pub fn keys_sorted_by_value_desc(&self) -> Vec<K>
+    where
+        V: Ord,
+    {
+        self.keys_sorted_by(|a, b| b.cmp(a))
+    }
+
+    /// Returns keys ordered by their values with a comparator function.
+    ///
+    /// The sort is stable, so keys with equal values are in the iteration
+    /// order.
+    ///
+    /// Requires crate feature `"alloc"`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::enum_map;
+    ///
+    /// let map = enum_map! { false => "long", true => "a" };
+    /// assert_eq!(map.keys_sorted_by(|a, b| a.len().cmp(&b.len())), [true, false]);
+    /// ```
+    pub fn keys_sorted_by<F>(&self, mut compare: F) -> Vec<K>
+    where
+        F: FnMut(&V, &V) -> Ordering,
+    {
+        let values = self.as_slice();
+        let mut indices: Vec<usize> = (0..values.len()).collect();
+        indices.sort_by(|&a, &b| compare(&values[a], &values[b]));
+        indices.into_iter().map(K::from_usize).collect()
+    }
+}
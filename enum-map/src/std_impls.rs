@@ -0,0 +1,91 @@
+// SPDX-FileCopyrightText: 2023 Konrad Borowski <konrad@borowski.pw>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+extern crate std;
+
+use crate::{enum_map, EnumArray, EnumMap};
+use core::convert::TryFrom;
+use core::fmt::{self, Debug, Display, Formatter};
+use core::hash::{BuildHasher, Hash};
+use std::collections::HashMap;
+
+/// Error returned when converting a collection that doesn't contain
+/// every key into an enum map.
+///
+/// Requires crate feature `"std"`
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct MissingKeyError<K> {
+    key: K,
+}
+
+impl<K> MissingKeyError<K> {
+    /// Returns the first key that wasn't found.
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Consumes the error, returning the first key that wasn't found.
+    pub fn into_key(self) -> K {
+        self.key
+    }
+}
+
+impl<K: Debug> Display for MissingKeyError<K> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "missing key: {:?}", self.key)
+    }
+}
+
+impl<K: Debug> std::error::Error for MissingKeyError<K> {}
+
+impl<K: EnumArray<V>, V> EnumMap<K, V> {
+    /// Converts an enum map into a [`HashMap`].
+    ///
+    /// Requires crate feature `"std"`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enum_map::enum_map;
+    ///
+    /// let map = enum_map! { false => 1, true => 2 };
+    /// let hash_map = map.into_hash_map();
+    /// assert_eq!(hash_map[&true], 2);
+    /// ```
+    pub fn into_hash_map(self) -> HashMap<K, V>
+    where
+        K: Eq + Hash,
+    {
+        self.into()
+    }
+}
+
+/// Requires crate feature `"std"`
+impl<K, V, S> From<EnumMap<K, V>> for HashMap<K, V, S>
+where
+    K: EnumArray<V> + Eq + Hash,
+    S: BuildHasher + Default,
+{
+    fn from(map: EnumMap<K, V>) -> Self {
+        map.into_iter().collect()
+    }
+}
+
+/// Fails with [`MissingKeyError`] reporting the first missing key if the
+/// hash map doesn't contain every key.
+///
+/// Requires crate feature `"std"`
+impl<K, V, S> TryFrom<HashMap<K, V, S>> for EnumMap<K, V>
+where
+    K: EnumArray<V> + Eq + Hash,
+    S: BuildHasher,
+{
+    type Error = MissingKeyError<K>;
+
+    fn try_from(mut map: HashMap<K, V, S>) -> Result<Self, Self::Error> {
+        Ok(enum_map! {
+            key => map.remove(&key).ok_or(MissingKeyError { key })?,
+        })
+    }
+}
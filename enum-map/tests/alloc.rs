@@ -0,0 +1,51 @@
+#![cfg(feature = "alloc")]
+
+// SPDX-FileCopyrightText: 2023 Konrad Borowski <konrad@borowski.pw>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use enum_map::{enum_map, Enum, EnumMap};
+
+#[derive(Debug, Enum, PartialEq)]
+enum Example {
+    A,
+    B(bool),
+}
+
+#[derive(Debug, Enum, PartialEq)]
+enum Void {}
+
+#[test]
+fn keys_sorted_by_value() {
+    let map = enum_map! { Example::A => 2, Example::B(false) => 1, Example::B(true) => 2 };
+    assert_eq!(
+        map.keys_sorted_by_value(),
+        [Example::B(false), Example::A, Example::B(true)],
+    );
+    assert_eq!(
+        map.keys_sorted_by_value_desc(),
+        [Example::A, Example::B(true), Example::B(false)],
+    );
+    assert_eq!(
+        map.keys_sorted_by(|a, b| (a % 2).cmp(&(b % 2))),
+        [Example::A, Example::B(true), Example::B(false)],
+    );
+}
+
+#[test]
+fn keys_sorted_by_value_empty() {
+    let map: EnumMap<Void, i32> = enum_map! {};
+    assert_eq!(map.keys_sorted_by_value(), []);
+}
+
+#[test]
+fn collect_grouped() {
+    let grouped = EnumMap::collect_grouped(vec![
+        (Example::B(true), "b1"),
+        (Example::A, "a1"),
+        (Example::B(true), "b2"),
+    ]);
+    assert_eq!(grouped[Example::A], ["a1"]);
+    assert!(grouped[Example::B(false)].is_empty());
+    assert_eq!(grouped[Example::B(true)], ["b1", "b2"]);
+}
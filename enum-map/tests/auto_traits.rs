@@ -0,0 +1,63 @@
+// SPDX-FileCopyrightText: 2023 Konrad Borowski <konrad@borowski.pw>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use enum_map::{Enum, EnumArray, EnumMap, IntoIter, Iter, IterMut};
+use std::cell::Cell;
+use std::marker::PhantomData;
+use std::sync::MutexGuard;
+
+fn assert_send<T: Send>() {}
+fn assert_sync<T: Sync>() {}
+
+/// Key that is neither `Send` nor `Sync`, which shouldn't matter, as keys
+/// aren't stored.
+struct Key(PhantomData<*const ()>);
+
+impl Enum for Key {
+    const LENGTH: usize = 1;
+
+    fn from_usize(_: usize) -> Self {
+        Key(PhantomData)
+    }
+
+    fn into_usize(self) -> usize {
+        0
+    }
+}
+
+impl<V> EnumArray<V> for Key {
+    type Array = [V; 1];
+}
+
+#[test]
+fn enum_map() {
+    assert_send::<EnumMap<Key, i32>>();
+    assert_sync::<EnumMap<Key, i32>>();
+    // Send, but not Sync.
+    assert_send::<EnumMap<bool, Cell<i32>>>();
+    // Sync, but not Send.
+    assert_sync::<EnumMap<bool, MutexGuard<'static, i32>>>();
+}
+
+#[test]
+fn iter() {
+    assert_send::<Iter<'static, Key, i32>>();
+    assert_sync::<Iter<'static, Key, i32>>();
+    // Shared references are Send when values are Sync.
+    assert_send::<Iter<'static, bool, MutexGuard<'static, i32>>>();
+}
+
+#[test]
+fn iter_mut() {
+    assert_send::<IterMut<'static, Key, i32>>();
+    assert_sync::<IterMut<'static, Key, i32>>();
+    assert_send::<IterMut<'static, bool, Cell<i32>>>();
+}
+
+#[test]
+fn into_iter() {
+    assert_send::<IntoIter<Key, i32>>();
+    assert_sync::<IntoIter<Key, i32>>();
+    assert_send::<IntoIter<bool, Cell<i32>>>();
+}
@@ -0,0 +1,37 @@
+#![cfg(feature = "bytemuck")]
+
+// SPDX-FileCopyrightText: 2023 Konrad Borowski <konrad@borowski.pw>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use enum_map::{enum_map, Enum, EnumMap};
+
+#[derive(Debug, Enum, PartialEq)]
+enum Register {
+    A,
+    B,
+    C,
+}
+
+#[test]
+fn zeroed() {
+    let map: EnumMap<Register, u32> = bytemuck::Zeroable::zeroed();
+    assert_eq!(map, enum_map! { _ => 0 });
+}
+
+#[test]
+fn cast_to_bytes() {
+    let map = enum_map! { Register::A => 1u32, Register::B => 2, Register::C => 3 };
+    let bytes = bytemuck::bytes_of(&map);
+    assert_eq!(bytes.len(), 12);
+    assert_eq!(bytemuck::from_bytes::<EnumMap<Register, u32>>(bytes), &map);
+}
+
+#[test]
+fn cast_slice() {
+    let values = [1u16, 2, 3, 4, 5, 6];
+    let maps: &[EnumMap<Register, u16>] = bytemuck::cast_slice(&values);
+    assert_eq!(maps.len(), 2);
+    assert_eq!(maps[1][Register::A], 4);
+    assert_eq!(maps[1][Register::C], 6);
+}
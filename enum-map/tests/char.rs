@@ -0,0 +1,46 @@
+#![cfg(feature = "char-enum")]
+
+// SPDX-FileCopyrightText: 2023 Konrad Borowski <konrad@borowski.pw>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use enum_map::Enum;
+
+#[test]
+fn length() {
+    assert_eq!(char::LENGTH, 0x10F800);
+}
+
+#[test]
+fn skips_surrogates() {
+    assert_eq!('\u{D7FF}'.into_usize(), 0xD7FF);
+    assert_eq!('\u{E000}'.into_usize(), 0xD800);
+    assert_eq!(char::from_usize(0xD7FF), '\u{D7FF}');
+    assert_eq!(char::from_usize(0xD800), '\u{E000}');
+}
+
+#[test]
+fn round_trip() {
+    for c in ['\0', 'a', 'ż', '\u{FFFF}', char::MAX] {
+        assert_eq!(char::from_usize(c.into_usize()), c);
+    }
+    assert_eq!(char::MAX.into_usize(), char::LENGTH - 1);
+}
+
+#[test]
+#[should_panic]
+fn out_of_bounds() {
+    char::from_usize(char::LENGTH);
+}
+
+#[test]
+#[should_panic(expected = "index out of range for Enum::from_usize")]
+fn out_of_bounds_max() {
+    char::from_usize(usize::MAX);
+}
+
+#[test]
+fn try_from_usize_out_of_bounds() {
+    assert_eq!(char::try_from_usize(char::LENGTH), None);
+    assert_eq!(char::try_from_usize(usize::MAX), None);
+}
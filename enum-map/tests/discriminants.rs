@@ -0,0 +1,65 @@
+// SPDX-FileCopyrightText: 2023 Konrad Borowski <konrad@borowski.pw>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use enum_map::{enum_map, Enum, EnumMap};
+
+#[derive(Clone, Copy, Debug, Enum, PartialEq)]
+#[repr(i16)]
+enum Mixed {
+    Large = 1000,
+    Negative = -5,
+    Small = 3,
+    MoreNegative = -300,
+}
+
+#[test]
+fn dense_indices() {
+    assert_eq!(Mixed::LENGTH, 4);
+    assert_eq!(Mixed::Large.into_usize(), 0);
+    assert_eq!(Mixed::Negative.into_usize(), 1);
+    assert_eq!(Mixed::Small.into_usize(), 2);
+    assert_eq!(Mixed::MoreNegative.into_usize(), 3);
+}
+
+#[test]
+fn round_trip() {
+    for index in 0..Mixed::LENGTH {
+        assert_eq!(Mixed::from_usize(index).into_usize(), index);
+    }
+    assert_eq!(Mixed::try_from_usize(4), None);
+}
+
+#[test]
+fn declaration_order_is_iteration_order() {
+    let map = enum_map! {
+        Mixed::Large => 'a',
+        Mixed::Negative => 'b',
+        Mixed::Small => 'c',
+        Mixed::MoreNegative => 'd',
+    };
+    assert_eq!(map.as_slice(), ['a', 'b', 'c', 'd']);
+    assert_eq!(
+        map.iter().map(|(key, _)| key).collect::<Vec<_>>(),
+        [
+            Mixed::Large,
+            Mixed::Negative,
+            Mixed::Small,
+            Mixed::MoreNegative
+        ]
+    );
+}
+
+#[test]
+fn u8_discriminants() {
+    #[derive(Debug, Enum, PartialEq)]
+    #[repr(u8)]
+    enum Sparse {
+        A = 5,
+        B = 10,
+        C = 255,
+    }
+    let map: EnumMap<Sparse, u8> = enum_map! { key => key as u8 };
+    assert_eq!(map.len(), 3);
+    assert_eq!(map.into_array(), [5, 10, 255]);
+}
@@ -0,0 +1,88 @@
+// SPDX-FileCopyrightText: 2023 Konrad Borowski <konrad@borowski.pw>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use enum_map::{enum_map, Enum, EnumArray, EnumMap};
+use std::fmt::Debug;
+
+#[derive(Clone, Copy, Debug, Enum, PartialEq)]
+enum Unit {
+    A,
+    B,
+    C,
+}
+
+#[derive(Clone, Copy, Debug, Enum, PartialEq)]
+enum Discriminants {
+    A = 30,
+    B = 10,
+    C = 20,
+}
+
+#[derive(Clone, Copy, Debug, Enum, PartialEq)]
+enum Nested {
+    A(bool),
+    B,
+    C { unit: Unit, flag: bool },
+}
+
+fn assert_index_order<K>()
+where
+    K: EnumArray<usize> + Copy + Debug + PartialEq,
+{
+    let expected: Vec<K> = (0..K::LENGTH).map(K::from_usize).collect();
+    let mut map: EnumMap<K, usize> = enum_map! { key => key.into_usize() };
+    assert!(map.values().copied().eq(0..K::LENGTH));
+    assert_eq!(map.iter().map(|(key, _)| key).collect::<Vec<_>>(), expected);
+    assert_eq!(
+        map.iter_mut().map(|(key, _)| key).collect::<Vec<_>>(),
+        expected
+    );
+    assert_eq!(
+        map.into_iter().map(|(key, _)| key).collect::<Vec<_>>(),
+        expected
+    );
+}
+
+#[test]
+fn unit() {
+    assert_index_order::<Unit>();
+    assert_eq!(
+        enum_map! { key => key }
+            .into_values()
+            .collect::<Vec<Unit>>(),
+        [Unit::A, Unit::B, Unit::C]
+    );
+}
+
+#[test]
+fn discriminants() {
+    assert_index_order::<Discriminants>();
+    assert_eq!(
+        enum_map! { key => key }
+            .into_values()
+            .collect::<Vec<Discriminants>>(),
+        [Discriminants::A, Discriminants::B, Discriminants::C]
+    );
+}
+
+#[test]
+fn nested() {
+    assert_index_order::<Nested>();
+    let keys: Vec<Nested> = enum_map! { key => key }.into_values().collect();
+    assert_eq!(keys.len(), 9);
+    assert_eq!(keys[..3], [Nested::A(false), Nested::A(true), Nested::B]);
+    assert_eq!(
+        keys[3..5],
+        [
+            Nested::C {
+                unit: Unit::A,
+                flag: false
+            },
+            Nested::C {
+                unit: Unit::B,
+                flag: false
+            },
+        ]
+    );
+}
@@ -0,0 +1,36 @@
+#![cfg(feature = "proptest")]
+
+// SPDX-FileCopyrightText: 2023 Konrad Borowski <konrad@borowski.pw>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use enum_map::{enum_map_strategy, Enum, EnumMap};
+use proptest::prelude::*;
+
+#[derive(Clone, Copy, Debug, Enum, PartialEq)]
+enum Example {
+    A,
+    B,
+    C,
+}
+
+#[derive(Debug, Enum)]
+enum Void {}
+
+proptest! {
+    #[test]
+    fn arbitrary_round_trips(map in any::<EnumMap<Example, u8>>()) {
+        let array = map.into_array();
+        prop_assert_eq!(EnumMap::<Example, u8>::from_array(array), map);
+    }
+
+    #[test]
+    fn strategy_respects_value_strategy(map in enum_map_strategy::<Example, _>(5..10)) {
+        prop_assert!(map.values().all(|value| (5..10).contains(value)));
+    }
+
+    #[test]
+    fn empty_enum(map in any::<EnumMap<Void, u8>>()) {
+        prop_assert_eq!(map.len(), 0);
+    }
+}
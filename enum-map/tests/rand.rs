@@ -0,0 +1,70 @@
+#![cfg(feature = "rand")]
+
+// SPDX-FileCopyrightText: 2023 Konrad Borowski <konrad@borowski.pw>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use enum_map::{enum_map, Enum, EnumMap};
+use rand::rngs::SmallRng;
+use rand::SeedableRng;
+
+#[derive(Debug, Enum, PartialEq)]
+enum Example {
+    A,
+    B,
+    C,
+}
+
+#[derive(Enum)]
+enum Void {}
+
+#[test]
+fn sample_key_covers_all_keys() {
+    let map = enum_map! { Example::A => 1, Example::B => 2, Example::C => 3 };
+    let mut rng = SmallRng::seed_from_u64(42);
+    let mut seen = EnumMap::<Example, usize>::default();
+    for _ in 0..300 {
+        seen[map.sample_key(&mut rng).unwrap()] += 1;
+    }
+    assert!(seen.values().all(|&count| count > 50));
+}
+
+#[test]
+fn sample_returns_matching_value() {
+    let map = enum_map! { Example::A => 1, Example::B => 2, Example::C => 3 };
+    let mut rng = SmallRng::seed_from_u64(42);
+    for _ in 0..20 {
+        let (key, &value) = map.sample(&mut rng).unwrap();
+        assert_eq!(value, map[key]);
+    }
+}
+
+#[test]
+fn sample_empty() {
+    let map: EnumMap<Void, i32> = enum_map! {};
+    let mut rng = SmallRng::seed_from_u64(42);
+    assert!(map.sample_key(&mut rng).is_none());
+    assert!(map.sample(&mut rng).is_none());
+}
+
+#[test]
+fn weighted_sample_skips_zero_weight() {
+    let map = enum_map! { Example::A => 1.0, Example::B => 0.0, Example::C => 3.0 };
+    let mut rng = SmallRng::seed_from_u64(42);
+    let mut seen = EnumMap::<Example, usize>::default();
+    for _ in 0..400 {
+        seen[map.weighted_sample(&mut rng, |&w| w).unwrap()] += 1;
+    }
+    assert_eq!(seen[Example::B], 0);
+    assert!(seen[Example::A] > 50);
+    assert!(seen[Example::C] > seen[Example::A]);
+}
+
+#[test]
+fn weighted_sample_no_weight() {
+    let map = enum_map! { Example::A => 0.0, Example::B => -1.0, Example::C => f64::NAN };
+    let mut rng = SmallRng::seed_from_u64(42);
+    assert_eq!(map.weighted_sample(&mut rng, |&w| w), None);
+    let empty: EnumMap<Void, f64> = enum_map! {};
+    assert!(empty.weighted_sample(&mut rng, |&w| w).is_none());
+}
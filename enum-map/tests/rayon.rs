@@ -0,0 +1,71 @@
+#![cfg(feature = "rayon")]
+
+// SPDX-FileCopyrightText: 2023 Konrad Borowski <konrad@borowski.pw>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use enum_map::{enum_map, Enum, EnumMap};
+use rayon::prelude::*;
+
+#[derive(Clone, Copy, Debug, Enum, PartialEq)]
+enum Example {
+    A,
+    B,
+    C,
+}
+
+#[derive(Enum)]
+enum Void {}
+
+#[test]
+fn par_iter() {
+    let map = enum_map! { Example::A => 1, Example::B => 2, Example::C => 3 };
+    let mut pairs: Vec<_> = (&map).into_par_iter().collect();
+    pairs.sort_by_key(|&(_, &value)| value);
+    assert_eq!(
+        pairs,
+        [(Example::A, &1), (Example::B, &2), (Example::C, &3)]
+    );
+}
+
+#[test]
+fn par_iter_mut() {
+    let mut map: EnumMap<u8, u32> = enum_map! { i => i.into() };
+    (&mut map)
+        .into_par_iter()
+        .for_each(|(key, value)| *value += u32::from(key));
+    let expected: EnumMap<u8, u32> = enum_map! { i => u32::from(i) * 2 };
+    assert_eq!(map, expected);
+}
+
+#[test]
+fn into_par_iter() {
+    let map: EnumMap<u8, u32> = enum_map! { i => i.into() };
+    assert!(map
+        .into_par_iter()
+        .all(|(key, value)| u32::from(key) == value));
+}
+
+#[test]
+fn par_map() {
+    let map: EnumMap<u8, String> = enum_map! { i => i.to_string() };
+    let lengths: EnumMap<u8, usize> = map.par_map(|_, value| value.len());
+    assert_eq!(lengths[9], 1);
+    assert_eq!(lengths[10], 2);
+    assert_eq!(lengths[100], 3);
+}
+
+#[test]
+fn from_par_fn() {
+    let map: EnumMap<u8, String> = EnumMap::from_par_fn(|key: u8| key.to_string());
+    assert_eq!(map[0], "0");
+    assert_eq!(map[255], "255");
+    let map: EnumMap<Example, Example> = EnumMap::from_par_fn(|key| key);
+    assert_eq!(map.as_slice(), [Example::A, Example::B, Example::C]);
+}
+
+#[test]
+fn from_par_fn_empty() {
+    let map: EnumMap<Void, i32> = EnumMap::from_par_fn(|_| unreachable!());
+    assert_eq!(map.len(), 0);
+}
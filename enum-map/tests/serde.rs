@@ -5,11 +5,11 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use enum_map::{enum_map, Enum, EnumMap};
+use enum_map::{enum_map, AsSeq, Enum, EnumMap, EnumSet, WithDefaults};
 use serde::{Deserialize, Serialize};
 use serde_test::{assert_de_tokens_error, assert_tokens, Compact, Configure, Token};
 
-#[derive(Debug, Enum, Deserialize, Serialize)]
+#[derive(Debug, Enum, Deserialize, PartialEq, Serialize)]
 enum Example {
     A,
     B,
@@ -87,6 +87,15 @@ fn json_invalid_deserialization() {
     assert!(example.is_err());
 }
 
+#[test]
+fn json_missing_key() {
+    let example: Result<EnumMap<Example, i32>, _> = serde_json::from_str(r#"{"A": 5}"#);
+    assert_eq!(
+        example.unwrap_err().to_string(),
+        "missing key at index 1 at line 1 column 8"
+    );
+}
+
 #[test]
 fn json_invalid_type() {
     let example: Result<EnumMap<Example, i32>, _> = serde_json::from_str("4");
@@ -100,6 +109,16 @@ fn json_invalid_key() {
     assert!(example.is_err());
 }
 
+#[test]
+fn json_duplicate_key() {
+    let example: Result<EnumMap<Example, i32>, _> =
+        serde_json::from_str(r#"{"A": 5, "A": 6, "B": 10}"#);
+    assert_eq!(
+        example.unwrap_err().to_string(),
+        "duplicate key at line 1 column 15"
+    );
+}
+
 #[test]
 fn bincode_serialization() {
     let example = enum_map! { false => 3u8, true => 4u8 };
@@ -113,3 +132,157 @@ fn bincode_too_short_deserialization() {
         bincode::deserialize::<EnumMap<bool, bool>>(&bincode::serialize(&()).unwrap()).is_err()
     );
 }
+
+#[test]
+fn as_seq_serialization() {
+    let map = enum_map! { Example::A => 5, Example::B => 10 };
+    assert_tokens(
+        &AsSeq(map).readable(),
+        &[
+            Token::Tuple { len: 2 },
+            Token::I32(5),
+            Token::I32(10),
+            Token::TupleEnd,
+        ],
+    );
+}
+
+#[test]
+fn as_seq_json() {
+    let map = enum_map! { Example::A => 5, Example::B => 10 };
+    assert_eq!(serde_json::to_string(&AsSeq(&map)).unwrap(), "[5,10]");
+    let AsSeq(deserialized): AsSeq<EnumMap<Example, i32>> = serde_json::from_str("[5,10]").unwrap();
+    assert_eq!(deserialized, map);
+}
+
+#[test]
+fn as_seq_too_short() {
+    let result: Result<AsSeq<EnumMap<Example, i32>>, _> = serde_json::from_str("[5]");
+    assert!(result.is_err());
+}
+
+#[test]
+fn with_defaults_missing_key() {
+    let WithDefaults(map): WithDefaults<EnumMap<Example, i32>> =
+        serde_json::from_str(r#"{"B":10}"#).unwrap();
+    assert_eq!(map, enum_map! { Example::A => 0, Example::B => 10 });
+}
+
+#[test]
+fn with_defaults_unknown_key() {
+    let result: Result<WithDefaults<EnumMap<Example, i32>>, _> =
+        serde_json::from_str(r#"{"B":10,"C":4}"#);
+    assert!(result.is_err());
+}
+
+#[test]
+fn with_defaults_duplicate_key() {
+    let result: Result<WithDefaults<EnumMap<Example, i32>>, _> =
+        serde_json::from_str(r#"{"B":10,"B":4}"#);
+    assert!(result.is_err());
+}
+
+#[test]
+fn with_defaults_serialization() {
+    let map = enum_map! { Example::A => 5, Example::B => 10 };
+    assert_eq!(
+        serde_json::to_string(&WithDefaults(map)).unwrap(),
+        String::from(JSON)
+    );
+}
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+struct IntKeys(#[serde(with = "enum_map::int_keys")] EnumMap<Example, i32>);
+
+#[test]
+fn int_keys_serialization() {
+    let map = IntKeys(enum_map! { Example::A => 5, Example::B => 10 });
+    assert_tokens(
+        &map,
+        &[
+            Token::NewtypeStruct { name: "IntKeys" },
+            Token::Map { len: Some(2) },
+            Token::U64(0),
+            Token::I32(5),
+            Token::U64(1),
+            Token::I32(10),
+            Token::MapEnd,
+        ],
+    );
+}
+
+#[test]
+fn int_keys_out_of_range() {
+    assert_de_tokens_error::<IntKeys>(
+        &[
+            Token::NewtypeStruct { name: "IntKeys" },
+            Token::Map { len: Some(1) },
+            Token::U64(2),
+        ],
+        "invalid value: integer `2`, expected an index of an enum variant",
+    );
+}
+
+#[test]
+fn int_keys_missing_key() {
+    assert_de_tokens_error::<IntKeys>(
+        &[
+            Token::NewtypeStruct { name: "IntKeys" },
+            Token::Map { len: Some(1) },
+            Token::U64(1),
+            Token::I32(10),
+            Token::MapEnd,
+        ],
+        "missing key at index 0",
+    );
+}
+
+#[test]
+fn int_keys_json() {
+    let map = IntKeys(enum_map! { Example::A => 5, Example::B => 10 });
+    let json = serde_json::to_string(&map).unwrap();
+    assert_eq!(json, r#"{"0":5,"1":10}"#);
+    assert_eq!(serde_json::from_str::<IntKeys>(&json).unwrap(), map);
+}
+
+#[test]
+fn set_serialization() {
+    let set: EnumSet<Example> = [Example::B].into_iter().collect();
+    assert_tokens(
+        &set,
+        &[
+            Token::Seq { len: Some(1) },
+            Token::UnitVariant {
+                name: "Example",
+                variant: "B",
+            },
+            Token::SeqEnd,
+        ],
+    );
+}
+
+#[test]
+fn set_json() {
+    let set: EnumSet<Example> = [Example::B, Example::A].into_iter().collect();
+    assert_eq!(serde_json::to_string(&set).unwrap(), r#"["A","B"]"#);
+    let set: EnumSet<Example> = serde_json::from_str(r#"["B"]"#).unwrap();
+    assert_eq!(set.iter().collect::<Vec<_>>(), [Example::B]);
+}
+
+#[test]
+fn set_unknown_key() {
+    assert!(serde_json::from_str::<EnumSet<Example>>(r#"["C"]"#).is_err());
+}
+
+#[test]
+fn set_duplicate_key() {
+    assert_de_tokens_error::<EnumSet<bool>>(
+        &[
+            Token::Seq { len: Some(2) },
+            Token::Bool(true),
+            Token::Bool(true),
+            Token::SeqEnd,
+        ],
+        "duplicate key",
+    );
+}
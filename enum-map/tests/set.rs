@@ -0,0 +1,95 @@
+// SPDX-FileCopyrightText: 2023 Konrad Borowski <konrad@borowski.pw>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use enum_map::{enum_map, Enum, EnumSet};
+use std::mem::size_of;
+
+#[derive(Clone, Copy, Debug, Enum, PartialEq)]
+enum Example {
+    A,
+    B,
+    C,
+}
+
+#[test]
+fn insert_remove() {
+    let mut set = EnumSet::new();
+    assert!(set.is_empty());
+    assert!(set.insert(Example::B));
+    assert!(!set.insert(Example::B));
+    assert!(set.contains(Example::B));
+    assert_eq!(set.len(), 1);
+    assert!(set.remove(Example::B));
+    assert!(!set.remove(Example::B));
+    assert!(!set.contains(Example::B));
+    assert!(set.is_empty());
+}
+
+#[test]
+fn union_intersection() {
+    let a: EnumSet<_> = [Example::A, Example::B].into_iter().collect();
+    let b: EnumSet<_> = [Example::B, Example::C].into_iter().collect();
+    let union: Vec<_> = a.union(&b).iter().collect();
+    assert_eq!(union, [Example::A, Example::B, Example::C]);
+    let intersection: Vec<_> = a.intersection(&b).iter().collect();
+    assert_eq!(intersection, [Example::B]);
+}
+
+#[test]
+fn iter() {
+    let set: EnumSet<_> = [Example::C, Example::A].into_iter().collect();
+    let mut iter = set.iter();
+    assert_eq!(iter.len(), 2);
+    assert_eq!(iter.next(), Some(Example::A));
+    assert_eq!(iter.len(), 1);
+    assert_eq!(iter.next(), Some(Example::C));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn map_round_trip() {
+    let map = enum_map! { Example::A => true, Example::B => false, Example::C => true };
+    let set = EnumSet::from_map(&map);
+    assert_eq!(set.len(), 2);
+    assert_eq!(set.to_map(), map);
+}
+
+#[test]
+fn u8_keys() {
+    let mut set = EnumSet::new();
+    set.insert(255u8);
+    set.insert(0);
+    set.insert(128);
+    assert_eq!(set.len(), 3);
+    assert_eq!(set.iter().collect::<Vec<_>>(), [0, 128, 255]);
+}
+
+#[test]
+fn struct_keys() {
+    #[derive(Debug, Enum, PartialEq)]
+    struct Wide(bool, bool, bool, bool, bool, bool, bool, bool, bool);
+
+    let mut set = EnumSet::new();
+    set.insert(Wide(true, true, true, true, true, true, true, true, true));
+    set.insert(Wide(
+        false, false, false, false, false, false, false, false, false,
+    ));
+    assert_eq!(set.len(), 2);
+    assert_eq!(
+        set.iter().last(),
+        Some(Wide(true, true, true, true, true, true, true, true, true))
+    );
+}
+
+#[test]
+fn packed_storage() {
+    assert_eq!(size_of::<EnumSet<u8>>(), 256 / 8);
+    assert_eq!(size_of::<EnumSet<bool>>(), size_of::<usize>());
+}
+
+#[test]
+fn debug() {
+    let set: EnumSet<_> = [Example::A, Example::C].into_iter().collect();
+    assert_eq!(format!("{:?}", set), "{A, C}");
+}
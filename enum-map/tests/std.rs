@@ -0,0 +1,45 @@
+#![cfg(feature = "std")]
+
+// SPDX-FileCopyrightText: 2023 Konrad Borowski <konrad@borowski.pw>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use enum_map::{enum_map, Enum, EnumMap, MissingKeyError};
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+#[derive(Debug, Enum, Eq, Hash, PartialEq)]
+enum Example {
+    A,
+    B,
+    C,
+}
+
+#[test]
+fn into_hash_map() {
+    let map = enum_map! { Example::A => 1, Example::B => 2, Example::C => 3 };
+    let hash_map = map.into_hash_map();
+    assert_eq!(hash_map.len(), 3);
+    assert_eq!(hash_map[&Example::B], 2);
+}
+
+#[test]
+fn from_enum_map() {
+    let hash_map: HashMap<_, _> = enum_map! { false => 'a', true => 'b' }.into();
+    assert_eq!(hash_map, HashMap::from([(false, 'a'), (true, 'b')]));
+}
+
+#[test]
+fn try_from_hash_map() {
+    let hash_map = HashMap::from([(Example::C, 3), (Example::A, 1), (Example::B, 2)]);
+    let map = EnumMap::<Example, i32>::try_from(hash_map).unwrap();
+    assert_eq!(map.as_slice(), &[1, 2, 3]);
+}
+
+#[test]
+fn try_from_hash_map_missing_key() {
+    let hash_map = HashMap::from([(Example::A, 1), (Example::C, 3)]);
+    let error: MissingKeyError<Example> = EnumMap::<_, i32>::try_from(hash_map).unwrap_err();
+    assert_eq!(*error.key(), Example::B);
+    assert_eq!(error.to_string(), "missing key: B");
+}
@@ -10,13 +10,17 @@
 #[macro_use]
 extern crate enum_map;
 
-use enum_map::{Enum, EnumArray, EnumMap, IntoIter};
+use enum_map::{Enum, EnumArray, EnumMap, EnumSet, IntoIter, Iter, Scalar, Values, ValuesMut};
 
 use std::cell::{Cell, RefCell};
+use std::cmp::Ordering;
 use std::collections::HashSet;
 use std::convert::Infallible;
+use std::fmt::{self, Debug, Display, Formatter};
+use std::iter::FusedIterator;
 use std::marker::PhantomData;
 use std::num::ParseIntError;
+use std::ops::ControlFlow;
 use std::panic::{catch_unwind, UnwindSafe};
 
 trait From<T>: Sized {
@@ -78,6 +82,1164 @@ fn test_clear() {
     assert_eq!(map[false], 0);
 }
 
+#[test]
+fn test_uninit_assume_init() {
+    let mut map = EnumMap::<Example, std::mem::MaybeUninit<Vec<i32>>>::uninit();
+    for (key, value) in &mut map {
+        value.write(vec![key.into_usize() as i32]);
+    }
+    let map = unsafe { map.assume_init() };
+    assert_eq!(map[Example::A], [0]);
+    assert_eq!(map[Example::C], [2]);
+}
+
+#[test]
+fn test_map_ref() {
+    let map = enum_map! { Example::A => "a".to_string(), Example::B => "bb".to_string(), Example::C => String::new() };
+    let lengths = map.map_ref(|key, value| (key, value.len()));
+    assert_eq!(
+        lengths.as_slice(),
+        [(Example::A, 1), (Example::B, 2), (Example::C, 0)]
+    );
+    assert_eq!(map[Example::B], "bb");
+}
+
+#[test]
+fn test_take() {
+    let mut map =
+        enum_map! { Example::A => vec![1], Example::B => vec![2, 3], Example::C => vec![] };
+    assert_eq!(map.take(Example::B), [2, 3]);
+    assert_eq!(map.take(Example::B), []);
+    assert_eq!(map[Example::A], [1]);
+}
+
+#[test]
+fn test_retain() {
+    let mut map = enum_map! {
+        Example::A => "a".to_string(),
+        Example::B => "b".to_string(),
+        Example::C => "c".to_string(),
+    };
+    map.retain(|key, _| key == Example::A);
+    assert_eq!(
+        map,
+        enum_map! { Example::A => "a".to_string(), _ => String::new() }
+    );
+}
+
+#[test]
+fn test_move_value() {
+    let mut map = enum_map! {
+        Example::A => "moved".to_string(),
+        Example::B => "overwritten".to_string(),
+        Example::C => "kept".to_string(),
+    };
+    map.move_value(Example::A, Example::B);
+    assert_eq!(map[Example::A], "");
+    assert_eq!(map[Example::B], "moved");
+    assert_eq!(map[Example::C], "kept");
+    map.move_value(Example::C, Example::C);
+    assert_eq!(map[Example::C], "kept");
+}
+
+#[test]
+fn test_chunk_by_value() {
+    #[derive(Debug, Enum, PartialEq)]
+    enum Sensor {
+        A,
+        B,
+        C,
+        D,
+        E,
+    }
+
+    let map = enum_map! {
+        Sensor::A => 1.0,
+        Sensor::B => 1.05,
+        Sensor::C => 3.0,
+        Sensor::D => 3.1,
+        Sensor::E => 3.15,
+    };
+    let runs: Vec<_> = map
+        .chunk_by_value(|a: &f64, b: &f64| (a - b).abs() < 0.2)
+        .collect();
+    assert_eq!(
+        runs,
+        [
+            (Sensor::A, Sensor::B, &[1.0, 1.05][..]),
+            (Sensor::C, Sensor::E, &[3.0, 3.1, 3.15][..]),
+        ]
+    );
+}
+
+#[test]
+fn test_try_reduce() {
+    let map = enum_map! { Example::A => 1, Example::B => 2, Example::C => 3 };
+    assert_eq!(
+        map.try_reduce(|acc, _, value| Ok::<_, ()>(acc + value)),
+        Ok(Some(6))
+    );
+}
+
+#[test]
+fn test_try_reduce_error() {
+    let dropped = RefCell::new(Vec::default());
+    let map: EnumMap<Example, _> = enum_map! {
+        k => DropReporter {
+            into: &dropped,
+            value: k as usize,
+        },
+    };
+    let result = map.try_reduce(|acc, key, value| {
+        if key == Example::B {
+            Err(value.value)
+        } else {
+            Ok(acc)
+        }
+    });
+    assert_eq!(result.err(), Some(1));
+    let mut dropped = dropped.into_inner();
+    dropped.sort_unstable();
+    assert_eq!(dropped, [0, 1, 2]);
+}
+
+#[test]
+fn test_try_reduce_empty() {
+    let map: EnumMap<Infallible, i32> = enum_map! {};
+    assert_eq!(map.try_reduce(|_, key, _| match key {}), Ok::<_, ()>(None));
+}
+
+#[test]
+fn test_as_chunks() {
+    #[derive(Enum)]
+    enum Register {
+        R0,
+        R1,
+        R2,
+        R3,
+        R4,
+        R5,
+        R6,
+        R7,
+    }
+
+    let map: EnumMap<Register, u32> = enum_map! { k => k as u32 };
+    let (chunks, remainder) = map.as_chunks::<4>();
+    assert_eq!(chunks, [[0, 1, 2, 3], [4, 5, 6, 7]]);
+    assert!(remainder.is_empty());
+    assert_eq!(map[Register::R5], 5);
+}
+
+#[test]
+fn test_reverse() {
+    #[derive(Debug, Enum)]
+    enum Level {
+        Low,
+        Medium,
+        High,
+        Critical,
+    }
+
+    let mut map = enum_map! {
+        Level::Low => 'a',
+        Level::Medium => 'b',
+        Level::High => 'c',
+        Level::Critical => 'd',
+    };
+    map.reverse();
+    assert_eq!(map[Level::Low], 'd');
+    assert_eq!(map[Level::Medium], 'c');
+    assert_eq!(map[Level::High], 'b');
+    assert_eq!(map[Level::Critical], 'a');
+}
+
+#[test]
+fn test_display() {
+    struct Key(Example);
+
+    impl Display for Key {
+        fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+            write!(f, "key {:?}", self.0)
+        }
+    }
+
+    impl Enum for Key {
+        const LENGTH: usize = Example::LENGTH;
+
+        fn from_usize(value: usize) -> Self {
+            Key(Example::from_usize(value))
+        }
+        fn into_usize(self) -> usize {
+            self.0.into_usize()
+        }
+    }
+
+    impl<V> EnumArray<V> for Key {
+        type Array = [V; Self::LENGTH];
+    }
+
+    let map = enum_map! { Key(Example::A) => 1, _ => 2 };
+    assert_eq!(map.display().to_string(), "{key A: 1, key B: 2, key C: 2}");
+    let empty: EnumMap<Infallible, u8> = enum_map! {};
+    assert_eq!(empty.display().to_string(), "{}");
+}
+
+#[test]
+fn test_remap() {
+    let map = enum_map! { Example::A => 1, Example::B => 2, Example::C => 3 };
+    let remapped = map.remap(|key| key != Example::A);
+    assert_eq!(remapped, enum_map! { false => 1, true => 3 });
+}
+
+#[test]
+fn test_cmp_by_value() {
+    let a = enum_map! { Example::A => 3, Example::B => 1, Example::C => 2 };
+    let b = enum_map! { Example::A => 1, Example::B => 2, Example::C => 3 };
+    let c = enum_map! { Example::A => 1, Example::B => 1, Example::C => 9 };
+    assert_eq!(a.cmp(&b), Ordering::Greater);
+    assert_eq!(a.cmp_by_value(&b), Ordering::Equal);
+    assert_eq!(a.cmp_by_value(&c), Ordering::Greater);
+    assert_eq!(c.cmp_by_value(&a), Ordering::Less);
+}
+
+#[test]
+fn test_scan() {
+    let map = enum_map! { Example::A => 1, Example::B => 2, Example::C => 3 };
+    let mut visited = Vec::new();
+    let sums = map.scan(10, |sum, key, value| {
+        visited.push(key);
+        *sum += value;
+        *sum
+    });
+    assert_eq!(
+        sums,
+        enum_map! { Example::A => 11, Example::B => 13, Example::C => 16 }
+    );
+    assert_eq!(visited, [Example::A, Example::B, Example::C]);
+}
+
+struct CountClones<'a>(&'a Cell<usize>, i32);
+
+impl Clone for CountClones<'_> {
+    fn clone(&self) -> Self {
+        self.0.set(self.0.get() + 1);
+        CountClones(self.0, self.1)
+    }
+}
+
+#[test]
+fn test_expand() {
+    let clones = Cell::new(0);
+    let map = enum_map! {
+        false => Some(CountClones(&clones, 1)),
+        true => Some(CountClones(&clones, 2)),
+    };
+    let expanded = map.expand(|key| {
+        if key {
+            vec![Example::C]
+        } else {
+            vec![Example::A, Example::B]
+        }
+    });
+    assert_eq!(clones.get(), 1);
+    let values: Vec<_> = expanded
+        .values()
+        .map(|value| value.as_ref().map(|value| value.1))
+        .collect();
+    assert_eq!(values, [Some(1), Some(1), Some(2)]);
+
+    let map = enum_map! { false => 1, true => 2 };
+    assert_eq!(map.expand(|_| [] as [Example; 0]).as_slice(), [0, 0, 0]);
+}
+
+#[test]
+fn test_upgrade_from() {
+    let old = enum_map! { false => "no", true => "yes" };
+    let new = EnumMap::upgrade_from(old, |key| if key { Example::C } else { Example::A });
+    assert_eq!(
+        new,
+        enum_map! { Example::A => "no", Example::B => "", Example::C => "yes" }
+    );
+}
+
+#[test]
+fn test_try_map_keys() {
+    let map = enum_map! { false => 1, true => 2 };
+    let remapped = map.try_map_keys(|key| Ok::<_, ()>(if key { Example::A } else { Example::C }));
+    assert_eq!(
+        remapped,
+        Ok(enum_map! { Example::A => 2, Example::B => 0, Example::C => 1 })
+    );
+}
+
+#[test]
+fn test_try_map_keys_error() {
+    let dropped = RefCell::new(Vec::default());
+    let map: EnumMap<Example, _> = enum_map! {
+        k => Some(DropReporter {
+            into: &dropped,
+            value: k as usize,
+        }),
+    };
+    let result = map.try_map_keys(|key| match key {
+        Example::A => Ok(false),
+        _ => Err("unmapped"),
+    });
+    assert_eq!(result.err(), Some("unmapped"));
+    let mut dropped = dropped.into_inner();
+    dropped.sort_unstable();
+    assert_eq!(dropped, [0, 1, 2]);
+}
+
+#[test]
+fn test_interleave() {
+    #[derive(Enum)]
+    enum Sample {
+        A0,
+        B0,
+        A1,
+        B1,
+    }
+
+    let first = enum_map! { false => 1, true => 2 };
+    let second = enum_map! { false => 3, true => 4 };
+    let map: EnumMap<Sample, _> = first.interleave(second);
+    assert_eq!(map.as_slice(), &[1, 3, 2, 4]);
+}
+
+#[test]
+#[should_panic(expected = "twice as many variants")]
+fn test_interleave_length_mismatch() {
+    let first = enum_map! { false => 1, true => 2 };
+    let second = enum_map! { false => 3, true => 4 };
+    let _: EnumMap<Example, _> = first.interleave(second);
+}
+
+#[test]
+fn test_layout_matches_array() {
+    use std::mem::{align_of, size_of};
+    assert_eq!(size_of::<EnumMap<Example, u32>>(), size_of::<[u32; 3]>());
+    assert_eq!(align_of::<EnumMap<Example, u64>>(), align_of::<[u64; 3]>());
+    assert_eq!(size_of::<EnumMap<Void, u8>>(), 0);
+}
+
+#[test]
+fn large_product_key() {
+    #[derive(Debug, Enum, PartialEq)]
+    struct Large(u8, u8, bool, u8);
+
+    assert_eq!(Large::LENGTH, 1 << 25);
+    let last = Large(255, 255, true, 255);
+    assert_eq!(Large::from_usize(Large::LENGTH - 1), last);
+    assert_eq!(last.into_usize(), Large::LENGTH - 1);
+    assert_eq!(
+        Large(1, 2, false, 3).into_usize(),
+        1 + 2 * 256 + 3 * 256 * 256 * 2
+    );
+}
+
+#[test]
+fn test_max_min_key() {
+    let map = enum_map! { Example::A => 3, Example::B => 1, Example::C => 3 };
+    assert_eq!(map.max_key(), Some(Example::A));
+    assert_eq!(map.min_key(), Some(Example::B));
+    assert_eq!(map.max_key_by(|a, b| b.cmp(a)), Some(Example::B));
+    assert_eq!(map.min_key_by(|a, b| b.cmp(a)), Some(Example::A));
+    assert_eq!(map.max_key_by_key(|&x| x % 3), Some(Example::B));
+    assert_eq!(map.min_key_by_key(|&x| x % 3), Some(Example::A));
+}
+
+#[test]
+fn test_max_key_empty() {
+    let map: EnumMap<Void, i32> = enum_map! {};
+    assert!(map.max_key().is_none());
+    assert!(map.min_key().is_none());
+    assert!(map.max_entry().is_none());
+    assert!(map.min_entry().is_none());
+}
+
+#[test]
+fn test_max_min_entry() {
+    let map = enum_map! { Example::A => 2, Example::B => 1, Example::C => 2 };
+    assert_eq!(map.max_entry(), Some((Example::A, &2)));
+    assert_eq!(map.min_entry(), Some((Example::B, &1)));
+}
+
+#[test]
+fn test_find_key() {
+    let map = enum_map! { Example::A => 1, Example::B => 2, Example::C => 2 };
+    assert_eq!(map.find_key(|&x| x == 2), Some(Example::B));
+    assert_eq!(map.find_key(|&x| x == 3), None);
+}
+
+#[test]
+fn test_find_value_mut() {
+    let mut map = enum_map! { Example::A => 1, Example::B => 2, Example::C => 2 };
+    let (key, value) = map.find_value_mut(|&x| x == 2).unwrap();
+    assert_eq!(key, Example::B);
+    *value = 5;
+    assert_eq!(map.as_slice(), &[1, 5, 2]);
+    assert!(map.find_value_mut(|&x| x == 3).is_none());
+}
+
+#[test]
+fn test_fill() {
+    let clones = Cell::new(0);
+    let mut map: EnumMap<Example, _> = enum_map! { _ => CountClones(&clones, 1) };
+    map.fill(CountClones(&clones, 2));
+    assert_eq!(clones.get(), 2);
+
+    let mut map = enum_map! { Example::A => 1, Example::B => 2, Example::C => 3 };
+    map.fill(4);
+    assert_eq!(map.as_slice(), &[4, 4, 4]);
+}
+
+#[test]
+fn test_clear_with() {
+    let mut map = enum_map! { Example::A => "a".to_string(), Example::B => "b".to_string(), Example::C => "c".to_string() };
+    let mut visited = Vec::new();
+    map.clear_with(|key| {
+        visited.push(key);
+        format!("{key:?}")
+    });
+    assert_eq!(visited, [Example::A, Example::B, Example::C]);
+    assert_eq!(map.as_slice(), ["A", "B", "C"]);
+}
+
+#[test]
+fn test_try_clone_error() {
+    let dropped = RefCell::new(Vec::default());
+    let map: EnumMap<Example, _> = enum_map! {
+        k => DropReporter {
+            into: &dropped,
+            value: k as usize,
+        },
+    };
+    let result = map.try_clone(|reporter| {
+        if reporter.value == 2 {
+            Err("cannot clone")
+        } else {
+            Ok(DropReporter {
+                into: &dropped,
+                value: reporter.value + 10,
+            })
+        }
+    });
+    assert_eq!(result.err(), Some("cannot clone"));
+    assert_eq!(*dropped.borrow(), &[10, 11]);
+    drop(map);
+    assert_eq!(*dropped.borrow(), &[10, 11, 0, 1, 2]);
+}
+
+#[test]
+fn test_replace() {
+    let mut map = enum_map! { Example::A => 1, Example::B => 2, Example::C => 3 };
+    assert_eq!(map.replace(Example::B, 5), 2);
+    assert_eq!(map.as_slice(), &[1, 5, 3]);
+}
+
+#[test]
+fn test_is_default() {
+    let mut map = enum_map! { Example::A => 1, Example::B => 0, Example::C => 0 };
+    assert!(!map.is_default());
+    map.clear();
+    assert!(map.is_default());
+}
+
+#[test]
+fn iterators_are_fused() {
+    fn assert_fused<I: FusedIterator>(_: I) {}
+
+    let mut map = enum_map! { Example::A => 1, Example::B => 2, Example::C => 3 };
+    assert_fused(map.iter());
+    assert_fused(map.iter_mut());
+    assert_fused(map.values());
+    assert_fused(map.values_mut());
+    assert_fused(map.into_iter());
+    assert_fused(map.into_values());
+}
+
+#[test]
+fn array_round_trip() {
+    let mut map = EnumMap::<Example, _>::from_array([1, 2, 3]);
+    assert_eq!(map.as_array(), &[1, 2, 3]);
+    map.as_mut_array()[1] = 5;
+    assert_eq!(map[Example::B], 5);
+    let array: [i32; 3] = map.into_array();
+    assert_eq!(array, [1, 5, 3]);
+}
+
+#[test]
+fn try_from_array() {
+    let map = EnumMap::<Example, _>::try_from([1, 2, 3]).unwrap();
+    assert_eq!(map[Example::C], 3);
+    let map: Result<EnumMap<Example, _>, _> = [1, 2].try_into();
+    assert_eq!(map, Err([1, 2]));
+}
+
+#[test]
+fn try_from_array_length_mismatch_does_not_drop() {
+    let dropped = RefCell::new(Vec::default());
+    let reporter = |value| DropReporter {
+        into: &dropped,
+        value,
+    };
+    let result = EnumMap::<bool, _>::try_from([0, 1, 2].map(reporter));
+    assert!(dropped.borrow().is_empty());
+    drop(result);
+    assert_eq!(*dropped.borrow(), &[0, 1, 2]);
+    let map = EnumMap::<bool, _>::try_from([3, 4].map(reporter));
+    assert_eq!(*dropped.borrow(), &[0, 1, 2]);
+    drop(map);
+    assert_eq!(*dropped.borrow(), &[0, 1, 2, 3, 4]);
+}
+
+#[test]
+fn test_overwrite_from() {
+    let dropped = RefCell::new(Vec::default());
+    let mut map: EnumMap<Example, _> = enum_map! {
+        k => DropReporter {
+            into: &dropped,
+            value: k as usize,
+        },
+    };
+    let other = enum_map! {
+        k => DropReporter {
+            into: &dropped,
+            value: k as usize + 10,
+        },
+    };
+    map.overwrite_from(other);
+    assert_eq!(*dropped.borrow(), &[0, 1, 2]);
+    assert_eq!(map[Example::B].value, 11);
+    drop(map);
+    assert_eq!(*dropped.borrow(), &[0, 1, 2, 10, 11, 12]);
+}
+
+#[test]
+fn test_split_first_last() {
+    let map = enum_map! { Example::A => 1, Example::B => 2, Example::C => 3 };
+    assert_eq!(map.split_first(), Some((Example::A, &1, &[2, 3][..])));
+    assert_eq!(map.split_last(), Some((Example::C, &3, &[1, 2][..])));
+}
+
+#[test]
+fn test_split_first_last_empty() {
+    let map: EnumMap<Void, i32> = enum_map! {};
+    assert!(map.split_first().is_none());
+    assert!(map.split_last().is_none());
+}
+
+#[test]
+fn test_first_difference() {
+    let a = enum_map! { Example::A => 1, Example::B => 2, Example::C => 3 };
+    let b = enum_map! { Example::A => 1, Example::B => 2, Example::C => 4 };
+    assert_eq!(a.first_difference(&b), Some(Example::C));
+    assert_eq!(b.first_difference(&a), Some(Example::C));
+    assert_eq!(a.first_difference(&a), None);
+}
+
+#[test]
+fn extend_cloned() {
+    let source = enum_map! { Example::A => "a".to_string(), Example::B => "b".to_string(), Example::C => "c".to_string() };
+    let mut map: EnumMap<Example, String> = EnumMap::default();
+    map.extend(source.iter().filter(|&(key, _)| key != Example::B));
+    assert_eq!(map.as_slice(), &["a", "", "c"]);
+}
+
+#[test]
+fn test_reset_where_with() {
+    let mut map: EnumMap<u8, u32> = enum_map! { _ => 0 };
+    map.reset_where_with(
+        |key, _| key % 2 == 1,
+        |key| {
+            let key: u32 = key.into();
+            key * 2
+        },
+    );
+    assert_eq!(map[0], 0);
+    assert_eq!(map[1], 2);
+    assert_eq!(map[254], 0);
+    assert_eq!(map[255], 510);
+}
+
+#[test]
+fn test_binary_search_value() {
+    let thresholds = enum_map! { Example::A => 10, Example::B => 20, Example::C => 30 };
+    assert_eq!(thresholds.binary_search_value(&10), Ok(Example::A));
+    assert_eq!(thresholds.binary_search_value(&30), Ok(Example::C));
+    assert_eq!(thresholds.binary_search_value(&5), Err(Some(Example::A)));
+    assert_eq!(thresholds.binary_search_value(&25), Err(Some(Example::C)));
+    assert_eq!(thresholds.binary_search_value(&35), Err(None));
+}
+
+#[test]
+fn test_compose() {
+    #[derive(Clone, Copy, Debug, Enum, PartialEq)]
+    enum State {
+        Idle,
+        Running,
+        Stopped,
+    }
+
+    #[derive(Clone, Copy, Debug, Enum, PartialEq)]
+    enum Event {
+        Start,
+        Stop,
+    }
+
+    #[derive(Clone, Debug, PartialEq)]
+    enum Action {
+        Spawn,
+        Kill,
+    }
+
+    let events = enum_map! {
+        State::Idle | State::Stopped => Event::Start,
+        State::Running => Event::Stop,
+    };
+    let actions = enum_map! { Event::Start => Action::Spawn, Event::Stop => Action::Kill };
+    let composed = events.compose(&actions);
+    assert_eq!(composed[State::Idle], Action::Spawn);
+    assert_eq!(composed[State::Running], Action::Kill);
+    assert_eq!(composed[State::Stopped], Action::Spawn);
+}
+
+#[test]
+fn test_transition_table() {
+    #[derive(Clone, Copy, Debug, Enum, PartialEq)]
+    enum State {
+        Start,
+        SeenA,
+        Accept,
+    }
+
+    #[derive(Enum)]
+    enum Input {
+        A,
+        B,
+    }
+
+    // Accepts inputs ending with "AB".
+    let table = EnumMap::transition_table(|state, input| match (state, input) {
+        (_, Input::A) => State::SeenA,
+        (State::SeenA, Input::B) => State::Accept,
+        (_, Input::B) => State::Start,
+    });
+    assert_eq!(table.step(State::Start, Input::A), State::SeenA);
+    assert_eq!(
+        table.run(State::Start, [Input::B, Input::A, Input::A, Input::B]),
+        State::Accept
+    );
+    assert_eq!(
+        table.run(State::Start, [Input::A, Input::B, Input::B]),
+        State::Start
+    );
+    assert_eq!(table.run(State::SeenA, []), State::SeenA);
+}
+
+#[test]
+fn test_bitwise_operators() {
+    let a = enum_map! { Example::A => true, Example::B => true, Example::C => false };
+    let b = enum_map! { Example::A => true, Example::B => false, Example::C => false };
+    assert_eq!((a & b).as_slice(), [true, false, false]);
+    assert_eq!((a | b).as_slice(), [true, true, false]);
+    assert_eq!((a ^ b).as_slice(), [false, true, false]);
+    assert_eq!((!a).as_slice(), [false, false, true]);
+}
+
+#[test]
+fn test_bitwise_assign_operators() {
+    let mut flags = EnumMap::<Example, bool>::default();
+    flags |= enum_map! { Example::A => true, _ => false };
+    flags |= enum_map! { Example::C => true, _ => false };
+    assert_eq!(flags.as_slice(), [true, false, true]);
+    flags &= enum_map! { Example::A => true, _ => false };
+    assert_eq!(flags.as_slice(), [true, false, false]);
+    flags ^= enum_map! { _ => true };
+    assert_eq!(flags.as_slice(), [false, true, true]);
+}
+
+#[test]
+fn test_key_from_index() {
+    let map = enum_map! { Ordering::Less => 1, Ordering::Equal => 2, Ordering::Greater => 3 };
+    assert_eq!(map.key_from_index(0), Some(Ordering::Less));
+    assert_eq!(map.key_from_index(2), Some(Ordering::Greater));
+    assert_eq!(map.key_from_index(3), None);
+    assert_eq!(map.key_from_index(usize::MAX), None);
+    assert_eq!(map.key_from_index(1).map(|key| map[key]), Some(2));
+}
+
+#[test]
+fn test_indices() {
+    let map = enum_map! { Example::A => 1, Example::B => 2, Example::C => 3 };
+    assert_eq!(map.indices(), 0..3);
+    let keys: Vec<_> = map
+        .indices()
+        .filter(|&index| map.get_index(index) != Some(&2))
+        .filter_map(|index| map.key_from_index(index))
+        .collect();
+    assert_eq!(keys, [Example::A, Example::C]);
+    let empty: EnumMap<Void, i32> = enum_map! {};
+    assert!(empty.indices().is_empty());
+}
+
+#[test]
+fn test_try_from_usize() {
+    assert_eq!(bool::try_from_usize(0), Some(false));
+    assert_eq!(bool::try_from_usize(2), None);
+    assert_eq!(u8::try_from_usize(255), Some(255));
+    assert_eq!(u8::try_from_usize(256), None);
+    assert_eq!(Ordering::try_from_usize(2), Some(Ordering::Greater));
+    assert_eq!(Ordering::try_from_usize(usize::MAX), None);
+    assert_eq!(Example::try_from_usize(1), Some(Example::B));
+    assert_eq!(Example::try_from_usize(3), None);
+    assert_eq!(<()>::try_from_usize(1), None);
+}
+
+#[test]
+fn test_get_index() {
+    let mut map = enum_map! { Example::A => 1, Example::B => 2, Example::C => 3 };
+    assert_eq!(map.get_index(0), Some(&1));
+    assert_eq!(map.get_index(3), None);
+    assert_eq!(map.get_index(usize::MAX), None);
+    *map.get_index_mut(2).unwrap() += 10;
+    assert_eq!(map[Example::C], 13);
+    assert_eq!(map.get_index_mut(3), None);
+}
+
+#[test]
+fn test_at() {
+    struct Wrapped(Example);
+
+    impl std::convert::From<Wrapped> for Example {
+        fn from(wrapped: Wrapped) -> Self {
+            wrapped.0
+        }
+    }
+
+    let mut map = enum_map! { Example::A => 1, Example::B => 2, Example::C => 3 };
+    assert_eq!(*map.at(Wrapped(Example::B)), 2);
+    assert_eq!(*map.at(Example::C), 3);
+    *map.at_mut(Wrapped(Example::A)) = 10;
+    assert_eq!(map.as_slice(), [10, 2, 3]);
+}
+
+#[test]
+fn test_swap_take() {
+    let mut map = enum_map! { Example::A => 1, Example::B => 2, Example::C => 3 };
+    assert_eq!(map.swap_take(Example::A, Example::C), (1, 3));
+    assert_eq!(map.as_slice(), [3, 2, 1]);
+    assert_eq!(map.swap_take(Example::B, Example::B), (2, 2));
+    assert_eq!(map.as_slice(), [3, 2, 1]);
+}
+
+#[test]
+fn test_sum_product() {
+    let map = enum_map! { Example::A => 2, Example::B => 3, Example::C => 4 };
+    assert_eq!(map.sum(), 9);
+    assert_eq!(map.product(), 24);
+    let empty: EnumMap<Infallible, f64> = enum_map! {};
+    assert_eq!(empty.sum(), 0.0);
+    assert_eq!(empty.product(), 1.0);
+}
+
+#[test]
+fn test_sum_maps() {
+    let records = vec![
+        enum_map! { Example::A => 1, _ => 0 },
+        enum_map! { Example::B => 2, _ => 3 },
+        enum_map! { Example::A => 4, _ => 0 },
+    ];
+    let totals: EnumMap<_, i32> = records.into_iter().sum();
+    assert_eq!(totals.as_slice(), [8, 2, 3]);
+    let empty: EnumMap<Example, i32> = std::iter::empty().sum();
+    assert_eq!(empty.as_slice(), [0, 0, 0]);
+}
+
+#[test]
+fn test_neg() {
+    let map = enum_map! { Example::A => 1, Example::B => -2, Example::C => 0 };
+    assert_eq!((-map).as_slice(), [-1, 2, 0]);
+}
+
+#[test]
+fn test_scalar_ops() {
+    let map = enum_map! { Example::A => 1.0, Example::B => 2.0, Example::C => 4.0 };
+    assert_eq!((map * Scalar(0.5)).as_slice(), [0.5, 1.0, 2.0]);
+    assert_eq!((map + Scalar(1.0)).as_slice(), [2.0, 3.0, 5.0]);
+    assert_eq!((map - Scalar(1.0)).as_slice(), [0.0, 1.0, 3.0]);
+    let durations =
+        enum_map! { false => std::time::Duration::from_secs(1), true => std::time::Duration::ZERO };
+    assert_eq!(
+        (durations * Scalar(3)).as_slice(),
+        [std::time::Duration::from_secs(3), std::time::Duration::ZERO]
+    );
+}
+
+#[test]
+fn test_count_where_all_any() {
+    let map = enum_map! { Example::A => 1, Example::B => 2, Example::C => 3 };
+    assert_eq!(map.count_where(|&value| value >= 2), 2);
+    assert!(map.all(|&value| value > 0));
+    assert!(map.any(|&value| value == 2));
+    let mut visited = 0;
+    assert!(!map.all(|&value| {
+        visited += 1;
+        value < 2
+    }));
+    assert_eq!(visited, 2);
+    visited = 0;
+    assert!(map.any(|&value| {
+        visited += 1;
+        value == 1
+    }));
+    assert_eq!(visited, 1);
+    let empty: EnumMap<Infallible, i32> = enum_map! {};
+    assert_eq!(empty.count_where(|_| true), 0);
+    assert!(empty.all(|_| false));
+    assert!(!empty.any(|_| true));
+}
+
+#[test]
+fn test_mask() {
+    let map = enum_map! { Example::A => 3, Example::B => 0, Example::C => 7 };
+    let active = map.mask(|_, &value| value != 0);
+    assert_eq!(active.as_slice(), [true, false, true]);
+    let set = EnumSet::from_map(&active);
+    assert_eq!(set.len(), 2);
+    assert!(!set.contains(Example::B));
+}
+
+#[test]
+fn one_element_tuple() {
+    let map = enum_map! { (Example::A,) => 1, (Example::B,) => 2, (Example::C,) => 3 };
+    let plain = enum_map! { Example::A => 1, Example::B => 2, Example::C => 3 };
+    assert_eq!(map.as_slice(), plain.as_slice());
+    assert_eq!(map[(Example::B,)], plain[Example::B]);
+    assert_eq!(<(Example,)>::LENGTH, Example::LENGTH);
+    assert_eq!(<((),)>::from_usize(0), ((),));
+}
+
+#[test]
+fn two_element_tuple() {
+    let map: EnumMap<(Example, bool), usize> = enum_map! { key => key.into_usize() };
+    assert_eq!(map.as_slice(), [0, 1, 2, 3, 4, 5]);
+    assert_eq!(map[(Example::B, true)], 4);
+    assert_eq!(<(Example, bool)>::LENGTH, 6);
+    assert_eq!(<(Example, bool)>::from_usize(2), (Example::C, false));
+    assert_eq!(<(Example, bool)>::try_from_usize(6), None);
+}
+
+#[test]
+fn control_flow() {
+    let map = enum_map! { ControlFlow::Continue(()) => 1, ControlFlow::Break(()) => 2 };
+    assert_eq!(map.as_slice(), [1, 2]);
+    assert_eq!(map[ControlFlow::Break(())], 2);
+    type Flow = ControlFlow<Example, bool>;
+    assert_eq!(Flow::LENGTH, 5);
+    for index in 0..Flow::LENGTH {
+        assert_eq!(Flow::from_usize(index).into_usize(), index);
+    }
+    assert_eq!(Flow::from_usize(1), ControlFlow::Continue(true));
+    assert_eq!(Flow::from_usize(3), ControlFlow::Break(Example::B));
+    assert_eq!(Flow::try_from_usize(5), None);
+}
+
+#[test]
+fn nested_roundtrip() {
+    #[derive(Debug, Enum, PartialEq)]
+    struct Point {
+        x: bool,
+        y: Example,
+    }
+
+    #[derive(Debug, Enum, PartialEq)]
+    enum Inner {
+        Unit,
+        Pair(Example, bool),
+        Named { point: Point, flag: bool },
+    }
+
+    #[derive(Debug, Enum, PartialEq)]
+    enum Outer {
+        First,
+        Wrapped(Inner, Ordering),
+        Byte(u8),
+        Tuple((Example,), bool),
+        Last,
+    }
+
+    assert_eq!(Inner::LENGTH, 1 + 6 + 12);
+    assert_eq!(Outer::LENGTH, 1 + Inner::LENGTH * 3 + 256 + 6 + 1);
+    for index in 0..Outer::LENGTH {
+        assert_eq!(Outer::from_usize(index).into_usize(), index);
+    }
+    assert_eq!(Outer::try_from_usize(Outer::LENGTH), None);
+}
+
+#[test]
+fn bool_array() {
+    assert_eq!(<[bool; 3]>::LENGTH, 8);
+    for index in 0..<[bool; 3]>::LENGTH {
+        assert_eq!(<[bool; 3]>::from_usize(index).into_usize(), index);
+    }
+    assert_eq!([true, false, true].into_usize(), 5);
+    assert_eq!(<[bool; 3]>::from_usize(6), [false, true, true]);
+    assert_eq!(<[bool; 3]>::try_from_usize(8), None);
+    assert_eq!(<[bool; 0]>::LENGTH, 1);
+    let map: EnumMap<[bool; 3], i32> = enum_map! { [true, true, false] => 1, _ => 0 };
+    assert_eq!(map.len(), 8);
+    assert_eq!(map.as_slice()[3], 1);
+}
+
+#[test]
+fn generic_enum() {
+    #[derive(Debug, Enum, PartialEq)]
+    enum Generic<T: Enum, U>
+    where
+        U: Enum,
+    {
+        First(T),
+        Empty,
+        Both { t: T, u: U },
+    }
+
+    #[derive(Debug, Enum, PartialEq)]
+    struct Wrapper(Generic<Example, bool>);
+
+    type Concrete = Generic<Example, bool>;
+    assert_eq!(Concrete::LENGTH, 10);
+    for index in 0..Concrete::LENGTH {
+        assert_eq!(Concrete::from_usize(index).into_usize(), index);
+    }
+    assert_eq!(Concrete::from_usize(3), Generic::Empty);
+    assert_eq!(
+        Concrete::from_usize(9),
+        Generic::Both {
+            t: Example::C,
+            u: true
+        }
+    );
+    let map = enum_map! { Wrapper(Generic::Empty) => 1, _ => 0 };
+    assert_eq!(map.len(), 10);
+    assert_eq!(map[Wrapper(Generic::Empty)], 1);
+}
+
+#[test]
+fn generic_enum_key() {
+    #[derive(Debug, Enum, PartialEq)]
+    enum Generic<T, U> {
+        First(T),
+        Empty,
+        Both { t: T, u: U },
+    }
+
+    let mut map: EnumMap<Generic<Example, bool>, usize> = enum_map! { key => key.into_usize() };
+    assert_eq!(map.len(), 10);
+    assert_eq!(map.as_slice(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    assert_eq!(map[Generic::Empty], 3);
+    map[Generic::Both {
+        t: Example::B,
+        u: true,
+    }] = 42;
+    assert_eq!(map.as_slice(), [0, 1, 2, 3, 4, 5, 6, 7, 42, 9]);
+    for (key, &value) in &map {
+        if value != 42 {
+            assert_eq!(key.into_usize(), value);
+        }
+    }
+}
+
+#[test]
+fn default_variant() {
+    #[derive(Debug, Enum, PartialEq)]
+    #[enum_map(default = Medium)]
+    enum Level {
+        Low(bool),
+        Medium,
+        High,
+    }
+
+    #[derive(Debug, Enum, PartialEq)]
+    #[enum_map(default = Empty)]
+    enum Generic<T: Enum> {
+        Empty,
+        Item(T),
+    }
+
+    // Without the attribute, `Default` can still be derived.
+    #[derive(Debug, Default, Enum, PartialEq)]
+    enum Derived {
+        A,
+        #[default]
+        B,
+    }
+
+    assert_eq!(Level::default(), Level::Medium);
+    assert_eq!(Generic::<bool>::default(), Generic::Empty);
+    assert_eq!(Derived::default(), Derived::B);
+}
+
+mod facade {
+    pub(crate) use ::enum_map;
+}
+
+#[test]
+fn custom_crate_path() {
+    #[derive(Debug, Enum, PartialEq)]
+    #[enum_map(crate = facade::enum_map)]
+    enum Renamed {
+        A,
+        B(bool),
+    }
+
+    #[derive(Debug, Enum, PartialEq)]
+    #[enum_map(crate = crate::facade::enum_map)]
+    struct Pair(Renamed, bool);
+
+    assert_eq!(Renamed::LENGTH, 3);
+    assert_eq!(Renamed::from_usize(2), Renamed::B(true));
+    let map = enum_map! { Pair(Renamed::A, true) => 1, _ => 0 };
+    assert_eq!(map.len(), 6);
+    assert_eq!(map.as_slice(), [0, 0, 0, 1, 0, 0]);
+}
+
+#[test]
+fn large_enum() {
+    #[derive(Debug, Enum, PartialEq)]
+    enum Large {
+        V0,
+        V1,
+        V2,
+        V3,
+        V4,
+        V5,
+        V6,
+        V7,
+        V8,
+        V9,
+        V10,
+        V11,
+        V12,
+        V13,
+        V14,
+        V15,
+        V16,
+        V17,
+        V18,
+        V19,
+        V20,
+        V21,
+        V22,
+        V23,
+        V24,
+        V25,
+        V26,
+        V27,
+        V28,
+        V29,
+        V30,
+        V31,
+        V32,
+        V33,
+        V34,
+        V35,
+        V36,
+        V37,
+        V38,
+        V39,
+        V40,
+        V41,
+        V42,
+        V43,
+        V44,
+        V45,
+        V46,
+        V47,
+        V48,
+        V49,
+        V50,
+        V51,
+        V52,
+        V53,
+        V54,
+        V55,
+        V56,
+        V57,
+        V58,
+        V59,
+        V60,
+        V61,
+        V62,
+        V63,
+        V64,
+        V65,
+        V66,
+        V67,
+        V68,
+        V69,
+        V70,
+        V71,
+        V72,
+        V73,
+        V74,
+        V75,
+        V76,
+        V77,
+        V78,
+        V79,
+        V80,
+        V81,
+        V82,
+        V83,
+        V84,
+        V85,
+        V86,
+        V87,
+        V88,
+        V89,
+        V90,
+        V91,
+        V92,
+        V93,
+        V94,
+        V95,
+        V96,
+        V97,
+        V98,
+        V99,
+        V100,
+        V101,
+        V102,
+        V103,
+        V104,
+        V105,
+        V106,
+        V107,
+        V108,
+        V109,
+        V110,
+        V111,
+        V112,
+        V113,
+        V114,
+        V115,
+        V116,
+        V117,
+        V118,
+        V119,
+        WithFields(bool, Example),
+    }
+
+    assert_eq!(Large::LENGTH, 126);
+    assert_eq!(Large::V0.into_usize(), 0);
+    assert_eq!(Large::V119.into_usize(), 119);
+    assert_eq!(Large::WithFields(true, Example::C).into_usize(), 125);
+    assert_eq!(Large::from_usize(119), Large::V119);
+    assert_eq!(Large::from_usize(121), Large::WithFields(true, Example::A));
+}
+
 #[test]
 fn struct_of_enum() {
     #[derive(Copy, Clone, Debug, Enum, PartialEq)]
@@ -316,6 +1478,49 @@ fn iter_mut_next_back() {
     );
 }
 
+#[test]
+fn iter_mut_alternating_ends() {
+    let mut map: EnumMap<u8, (u8, bool)> = enum_map! { i => (i, false) };
+    let mut iter = map.iter_mut();
+    let mut front = true;
+    while let Some((key, (index, visited))) = if front { iter.next() } else { iter.next_back() } {
+        assert_eq!(key, *index);
+        assert!(!*visited);
+        *visited = true;
+        front = !front;
+    }
+    assert!(map.values().all(|&(_, visited)| visited));
+}
+
+#[test]
+fn split_at() {
+    let mut map = enum_map! { Example::A => 1, Example::B => 2, Example::C => 3 };
+    assert_eq!(map.split_at(Example::B), (&[1][..], &[2, 3][..]));
+    let (before, after) = map.split_at_mut(Example::A);
+    assert!(before.is_empty());
+    after[2] = 4;
+    assert_eq!(map.as_slice(), [1, 2, 4]);
+}
+
+#[test]
+fn split_iter_mut() {
+    let mut map = enum_map! { Example::A => 1, Example::B => 2, Example::C => 3 };
+    let (before, mut after) = map.split_iter_mut(Example::B);
+    assert_eq!(after.next_back(), Some((Example::C, &mut 3)));
+    assert_eq!(after.len(), 1);
+    for (key, value) in before.chain(after) {
+        *value += key.into_usize() * 10;
+    }
+    assert_eq!(map.as_slice(), [1, 12, 3]);
+}
+
+#[test]
+fn iter_mut_rev() {
+    let mut map = enum_map! { Example::A => 1, Example::B => 2, Example::C => 3 };
+    let keys: Vec<_> = map.iter_mut().rev().map(|(key, _)| key).collect();
+    assert_eq!(keys, [Example::C, Example::B, Example::A]);
+}
+
 #[test]
 fn into_iter() {
     let mut iter = enum_map! { true => 5, false => 7 }.into_iter();
@@ -370,6 +1575,57 @@ fn into_iter_double_ended_iterator() {
     assert!(iter.rev().eq((2..254).rev().map(|i| (i, 0))));
 }
 
+#[test]
+fn into_iter_double_ended_drop() {
+    let dropped = RefCell::new(Vec::default());
+    let mut iter: IntoIter<Example, _> = enum_map! {
+        k => DropReporter {
+            into: &dropped,
+            value: k as usize,
+        },
+    }
+    .into_iter();
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.next_back().unwrap().0, Example::C);
+    assert_eq!(*dropped.borrow(), &[2]);
+    assert_eq!(iter.size_hint(), (2, Some(2)));
+    drop(iter);
+    assert_eq!(*dropped.borrow(), &[2, 0, 1]);
+}
+
+#[test]
+fn into_iter_nth_drop() {
+    let dropped = RefCell::new(Vec::default());
+    let mut iter: IntoIter<Example, _> = enum_map! {
+        k => DropReporter {
+            into: &dropped,
+            value: k as usize,
+        },
+    }
+    .into_iter();
+    let (key, value) = iter.nth(1).unwrap();
+    assert_eq!(key, Example::B);
+    assert_eq!(*dropped.borrow(), &[0]);
+    drop(value);
+    assert_eq!(*dropped.borrow(), &[0, 1]);
+    assert_eq!(iter.len(), 1);
+    assert!(iter.nth(5).is_none());
+    assert_eq!(*dropped.borrow(), &[0, 1, 2]);
+    drop(iter);
+    assert_eq!(*dropped.borrow(), &[0, 1, 2]);
+}
+
+#[test]
+fn into_iter_nth() {
+    let mut iter = enum_map! { i => i }.into_iter();
+    assert_eq!(iter.nth(1), Some((1u8, 1)));
+    assert_eq!(iter.nth(8), Some((10, 10)));
+    assert_eq!(iter.next_back(), Some((255, 255)));
+    assert_eq!(iter.nth(243), Some((254, 254)));
+    assert_eq!(iter.nth(usize::MAX), None);
+    assert_eq!(iter.next(), None);
+}
+
 #[test]
 fn values_rev_collect() {
     assert_eq!(
@@ -387,6 +1643,16 @@ fn values_len() {
     assert_eq!(enum_map! { false => 0, true => 1 }.values().len(), 2);
 }
 
+#[test]
+fn values_mut_len_rev() {
+    let mut map = enum_map! { Example::A => 1, Example::B => 2, Example::C => 3 };
+    let mut values = map.values_mut();
+    assert_eq!(values.len(), 3);
+    *values.next_back().unwrap() = 5;
+    assert_eq!(values.len(), 2);
+    assert_eq!(map.as_slice(), &[1, 2, 5]);
+}
+
 #[test]
 fn into_values_rev_collect() {
     assert_eq!(
@@ -408,6 +1674,52 @@ fn values_mut_next_back() {
     let mut map = enum_map! { false => 0, true => 1 };
     assert_eq!(map.values_mut().next_back(), Some(&mut 1));
 }
+
+#[test]
+fn values_mut_alternating_ends() {
+    let mut map: EnumMap<u8, (u8, bool)> = enum_map! { i => (i, false) };
+    let mut values = map.values_mut();
+    let mut expected_front = 0;
+    let mut expected_back = 255;
+    while let Some((index, visited)) = values.next() {
+        assert_eq!(*index, expected_front);
+        assert!(!*visited);
+        *visited = true;
+        expected_front += 1;
+        if let Some((index, visited)) = values.next_back() {
+            assert_eq!(*index, expected_back);
+            assert!(!*visited);
+            *visited = true;
+            expected_back -= 1;
+        }
+    }
+    assert_eq!(expected_front, expected_back + 1);
+    assert!(map.values().all(|&(_, visited)| visited));
+}
+
+#[test]
+fn values_nameable_in_signatures() {
+    fn values_of(map: &EnumMap<Example, u8>) -> Values<'_, u8> {
+        map.values()
+    }
+    fn values_mut_of(map: &mut EnumMap<Example, u8>) -> ValuesMut<'_, u8> {
+        map.values_mut()
+    }
+    fn assert_iterator<I>(iter: I) -> I
+    where
+        I: Clone + Debug + DoubleEndedIterator + ExactSizeIterator + FusedIterator,
+    {
+        iter
+    }
+
+    let mut map = enum_map! { Example::A => 1, Example::B => 2, Example::C => 3 };
+    let values = assert_iterator(values_of(&map));
+    assert_eq!(format!("{:?}", values), "Values([1, 2, 3])");
+    for value in values_mut_of(&mut map).rev() {
+        *value *= 2;
+    }
+    assert_eq!(format!("{:?}", map.values_mut()), "ValuesMut([2, 4, 6])");
+}
 #[test]
 fn test_u8() {
     let mut map = enum_map! { b'a' => 4, _ => 0 };
@@ -418,9 +1730,69 @@ fn test_u8() {
     assert_eq!(map.iter().next(), Some((0, &0)));
 }
 
+#[test]
+fn test_u8_ranges() {
+    let map = enum_map! { b'a'..=b'z' => 1, b'0'..=b'4' => 2, _ => 0 };
+    assert_eq!(map.values().filter(|&&v| v == 1).count(), 26);
+    assert_eq!(map.values().filter(|&&v| v == 2).count(), 5);
+    assert_eq!(map[b'a'], 1);
+    assert_eq!(map[b'z'], 1);
+    assert_eq!(map[b'`'], 0);
+    assert_eq!(map[b'{'], 0);
+    assert_eq!(map[b'4'], 2);
+    assert_eq!(map[b'5'], 0);
+}
+
 #[derive(Enum)]
 enum Void {}
 
+#[test]
+fn const_enum_map() {
+    const MAP: EnumMap<Example, u8> = const_enum_map! {
+        Example;
+        Example::A | Example::C => 1,
+        Example::B => 2,
+    };
+    static STATIC_MAP: EnumMap<Example, char> = const_enum_map! { Example; _ => 'x' };
+    assert_eq!(MAP.as_slice(), [1, 2, 1]);
+    assert_eq!(STATIC_MAP.as_slice(), ['x', 'x', 'x']);
+}
+
+#[test]
+fn const_enum_map_built_in_keys() {
+    const BOOLS: EnumMap<bool, u8> = const_enum_map! { bool; false => 0, true => 1 };
+    const DIGITS: EnumMap<u8, bool> = const_enum_map! { u8; b'0'..=b'9' => true, _ => false };
+    assert_eq!(BOOLS[true], 1);
+    assert!(DIGITS[b'9']);
+    assert!(!DIGITS[b'a']);
+}
+
+#[test]
+fn const_enum_map_key_with_drop() {
+    #[derive(Enum)]
+    enum WithDrop {
+        A,
+        B,
+    }
+
+    impl Drop for WithDrop {
+        fn drop(&mut self) {}
+    }
+
+    const MAP: EnumMap<WithDrop, u8> = const_enum_map! {
+        WithDrop;
+        WithDrop::A => 1,
+        WithDrop::B => 2,
+    };
+    assert_eq!(MAP[WithDrop::B], 2);
+}
+
+#[test]
+fn const_enum_map_empty() {
+    const MAP: EnumMap<Void, u8> = const_enum_map! { Void; };
+    assert!(MAP.as_slice().is_empty());
+}
+
 #[test]
 fn empty_map() {
     let void: EnumMap<Void, Void> = enum_map! {};
@@ -513,6 +1885,15 @@ fn test_iter_clone() {
     assert_eq!(values.map(|S(v)| v).sum::<u8>(), 8);
 }
 
+#[test]
+fn test_iter_clone_without_bounds() {
+    fn clone_iter<'a, K, V>(iter: &Iter<'a, K, V>) -> Iter<'a, K, V> {
+        iter.clone()
+    }
+    let map = enum_map! { false => 1, true => 2 };
+    assert!(clone_iter(&map.iter()).eq(map.iter()));
+}
+
 #[test]
 fn question_mark() -> Result<(), ParseIntError> {
     let map = enum_map! { false => "2".parse()?, true => "5".parse()? };
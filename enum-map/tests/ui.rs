@@ -0,0 +1,12 @@
+// SPDX-FileCopyrightText: 2023 Konrad Borowski <konrad@borowski.pw>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// Compiler diagnostics change between versions, so these are only checked
+// with a stable compiler.
+#[rustversion::attr(not(stable), ignore)]
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
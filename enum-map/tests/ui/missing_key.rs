@@ -0,0 +1,19 @@
+// SPDX-FileCopyrightText: 2023 Konrad Borowski <konrad@borowski.pw>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use enum_map::{enum_map, Enum, EnumMap};
+
+#[derive(Enum)]
+enum Example {
+    A,
+    B,
+    C,
+}
+
+fn main() {
+    let _: EnumMap<Example, i32> = enum_map! {
+        Example::A => 1,
+        Example::B => 2,
+    };
+}
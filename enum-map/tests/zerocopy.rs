@@ -0,0 +1,44 @@
+#![cfg(feature = "zerocopy")]
+
+// SPDX-FileCopyrightText: 2023 Konrad Borowski <konrad@borowski.pw>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use enum_map::{enum_map, Enum, EnumMap};
+use zerocopy::{FromBytes, FromZeros, IntoBytes};
+
+#[derive(Debug, Enum, PartialEq)]
+enum FieldId {
+    Source,
+    Destination,
+    Length,
+}
+
+#[test]
+fn new_zeroed() {
+    let map: EnumMap<FieldId, u32> = FromZeros::new_zeroed();
+    assert_eq!(map, enum_map! { _ => 0 });
+}
+
+#[test]
+fn read_from_bytes() {
+    let frame = [1u16.to_ne_bytes(), 2u16.to_ne_bytes(), 3u16.to_ne_bytes()].concat();
+    let map = EnumMap::<FieldId, u16>::read_from_bytes(&frame).unwrap();
+    assert_eq!(map[FieldId::Destination], 2);
+    assert_eq!(map.as_bytes(), frame);
+}
+
+#[test]
+fn read_from_bytes_wrong_length() {
+    assert!(EnumMap::<FieldId, u16>::read_from_bytes(&[0; 4]).is_err());
+}
+
+#[test]
+fn unaligned_ref_from_bytes() {
+    let frame = [0u8, 1, 2, 3];
+    let map = EnumMap::<FieldId, u8>::ref_from_bytes(&frame[1..]).unwrap();
+    assert_eq!(
+        map,
+        &enum_map! { FieldId::Source => 1, FieldId::Destination => 2, FieldId::Length => 3 }
+    );
+}